@@ -1,10 +1,11 @@
 mod utils;
 use std::fs;
 
-use clap::Parser;
+use clap::{Parser, ValueEnum};
 use clar2wasm::CompileError;
 use clarity::vm::costs::LimitedCostTracker;
 use clarity::vm::database::MemoryBackingStore;
+use clarity::vm::diagnostic::{Diagnostic, Level};
 use clarity::vm::types::QualifiedContractIdentifier;
 use utils::{WrappedClarityVersion, WrappedEpochId};
 
@@ -23,6 +24,33 @@ struct Args {
     /// Output file to write compiled WebAssembly to
     #[arg(short, long)]
     output: Option<String>,
+    /// Format of the diagnostics printed when the compilation fails
+    #[arg(long, value_enum, default_value_t = DiagnosticsFormat::Text)]
+    format: DiagnosticsFormat,
+}
+
+#[derive(Clone, Copy, ValueEnum)]
+enum DiagnosticsFormat {
+    /// One human readable diagnostic per line on stderr
+    Text,
+    /// A JSON array of diagnostics, with their level, message and spans, on
+    /// stdout. Errors also have the code of the error.
+    Json,
+}
+
+/// Serializes `diagnostics` to a JSON array, adding `code` to the errors.
+fn json_diagnostics(diagnostics: &[Diagnostic], code: &str) -> serde_json::Result<String> {
+    let diagnostics = diagnostics
+        .iter()
+        .map(|diagnostic| {
+            let mut value = serde_json::to_value(diagnostic)?;
+            if let (Level::Error, Some(object)) = (&diagnostic.level, value.as_object_mut()) {
+                object.insert("code".to_owned(), code.into());
+            }
+            Ok(value)
+        })
+        .collect::<serde_json::Result<Vec<_>>>()?;
+    serde_json::to_string(&diagnostics)
 }
 
 fn main() {
@@ -66,9 +94,18 @@ fn main() {
             diagnostics,
             ast: _,
             cost_tracker: _,
+            code,
         } => {
-            for diagnostic in diagnostics.iter() {
-                eprintln!("{diagnostic}");
+            match args.format {
+                DiagnosticsFormat::Text => {
+                    for diagnostic in diagnostics.iter() {
+                        eprintln!("{diagnostic}");
+                    }
+                }
+                DiagnosticsFormat::Json => match json_diagnostics(&diagnostics, &code) {
+                    Ok(json) => println!("{json}"),
+                    Err(error) => eprintln!("Error serializing diagnostics: {}", error),
+                },
             }
             std::process::exit(1);
         }
@@ -0,0 +1,125 @@
+//! A cache of compiled modules on disk, so that tools compiling many
+//! contracts do not have to compile unchanged ones again.
+
+use std::path::PathBuf;
+
+use clarity::types::StacksEpochId;
+use clarity::vm::analysis::AnalysisDatabase;
+use clarity::vm::costs::LimitedCostTracker;
+use clarity::vm::types::QualifiedContractIdentifier;
+use clarity::vm::ClarityVersion;
+use walrus::Module;
+
+use crate::{compile, CompileError};
+
+/// A directory of compiled modules, keyed by a hash of their source.
+#[derive(Debug)]
+pub struct CompileCache {
+    dir: PathBuf,
+    hits: usize,
+}
+
+impl CompileCache {
+    /// Creates a cache storing its modules in `dir`. The directory is
+    /// created when the first module is written.
+    pub fn new(dir: impl Into<PathBuf>) -> Self {
+        Self {
+            dir: dir.into(),
+            hits: 0,
+        }
+    }
+
+    /// Number of compilations that were served from the cache.
+    pub fn hits(&self) -> usize {
+        self.hits
+    }
+
+    /// Returns the cached module for `source_hash` if there is one, otherwise
+    /// compiles `source` and stores the result in the cache.
+    ///
+    /// The hash is provided by the caller and should cover everything that
+    /// affects the compilation: the source, the contract identifier, the
+    /// Clarity version and the epoch.
+    /// Failing to read or write the cache is not an error, the contract is
+    /// compiled instead.
+    #[allow(clippy::too_many_arguments)]
+    pub fn compile(
+        &mut self,
+        source_hash: &str,
+        source: &str,
+        contract_id: &QualifiedContractIdentifier,
+        cost_tracker: LimitedCostTracker,
+        clarity_version: ClarityVersion,
+        epoch: StacksEpochId,
+        analysis_db: &mut AnalysisDatabase,
+    ) -> Result<Module, CompileError> {
+        let path = self.dir.join(format!("{source_hash}.wasm"));
+
+        if let Some(module) = std::fs::read(&path)
+            .ok()
+            .and_then(|wasm| Module::from_buffer(&wasm).ok())
+        {
+            self.hits += 1;
+            return Ok(module);
+        }
+
+        let mut module = compile(
+            source,
+            contract_id,
+            cost_tracker,
+            clarity_version,
+            epoch,
+            analysis_db,
+        )?
+        .module;
+
+        let _ = std::fs::create_dir_all(&self.dir)
+            .and_then(|_| std::fs::write(&path, module.emit_wasm()));
+
+        Ok(module)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use clarity::types::StacksEpochId;
+    use clarity::vm::analysis::AnalysisDatabase;
+    use clarity::vm::costs::LimitedCostTracker;
+    use clarity::vm::database::MemoryBackingStore;
+    use clarity::vm::types::{QualifiedContractIdentifier, StandardPrincipalData};
+    use clarity::vm::ClarityVersion;
+
+    use super::CompileCache;
+
+    #[test]
+    fn second_compile_reads_from_cache() {
+        let temp = assert_fs::TempDir::new().unwrap();
+        let mut cache = CompileCache::new(temp.path().join("modules"));
+        let contract_id =
+            QualifiedContractIdentifier::new(StandardPrincipalData::transient(), ("tmp").into());
+        let source = "(define-read-only (get-one) u1)";
+
+        let mut compile = |hash: &str| {
+            cache
+                .compile(
+                    hash,
+                    source,
+                    &contract_id,
+                    LimitedCostTracker::new_free(),
+                    ClarityVersion::Clarity2,
+                    StacksEpochId::Epoch25,
+                    &mut AnalysisDatabase::new(&mut MemoryBackingStore::new()),
+                )
+                .unwrap()
+        };
+
+        compile("abc");
+        let cached = compile("abc");
+        assert!(cached.exports.iter().any(|export| export.name == "get-one"));
+
+        // A different hash is a miss.
+        compile("def");
+
+        assert_eq!(cache.hits(), 1);
+    }
+}
@@ -38,17 +38,17 @@ pub struct Datastore {
 
 #[derive(Clone, Debug)]
 pub struct BlockInfo {
-    block_header_hash: BlockHeaderHash,
-    burn_block_header_hash: BurnchainHeaderHash,
-    consensus_hash: ConsensusHash,
-    vrf_seed: VRFSeed,
-    burn_block_time: u64,
-    burn_block_height: u32,
-    miner: StacksAddress,
-    burnchain_tokens_spent_for_block: u128,
-    get_burnchain_tokens_spent_for_winning_block: u128,
-    tokens_earned_for_block: u128,
-    pox_payout_addrs: (Vec<TupleData>, u128),
+    pub block_header_hash: BlockHeaderHash,
+    pub burn_block_header_hash: BurnchainHeaderHash,
+    pub consensus_hash: ConsensusHash,
+    pub vrf_seed: VRFSeed,
+    pub burn_block_time: u64,
+    pub burn_block_height: u32,
+    pub miner: StacksAddress,
+    pub burnchain_tokens_spent_for_block: u128,
+    pub get_burnchain_tokens_spent_for_winning_block: u128,
+    pub tokens_earned_for_block: u128,
+    pub pox_payout_addrs: (Vec<TupleData>, u128),
 }
 
 #[derive(Clone, Debug, Default)]
@@ -60,6 +60,26 @@ pub struct StacksConstants {
     pub epoch_21_start_height: u32,
 }
 
+/// The block and burn block information the host functions read, e.g. for
+/// `get-block-info?` or `at-block`.
+///
+/// A `ClarityDatabase` reads it through `HostDatastore`, and
+/// `call_function` and `initialize_contract` read it from the database of
+/// their `GlobalContext`. `BurnDatastore` is the in-memory implementation.
+pub trait HostInterface {
+    /// Returns the height of the chain tip.
+    fn tip_height(&self) -> u32;
+
+    /// Returns the height of the block `id`, if it is part of the chain.
+    fn block_height(&self, id: &StacksBlockId) -> Option<u32>;
+
+    /// Returns the information of the block at `height`, if it exists.
+    fn block_info(&self, height: u32) -> Option<BlockInfo>;
+
+    /// Returns the PoX constants of the chain.
+    fn constants(&self) -> &StacksConstants;
+}
+
 #[derive(Clone, Debug)]
 pub struct BurnDatastore {
     store: HashMap<StacksBlockId, BlockInfo>,
@@ -241,7 +261,7 @@ impl ClarityBackingStore for Datastore {
     ///   used to implement time-shifted evaluation.
     /// returns the previous block header hash on success
     fn set_block_hash(&mut self, bhh: StacksBlockId) -> Result<StacksBlockId> {
-        let prior_tip = self.open_chain_tip;
+        let prior_tip = self.current_chain_tip;
         self.current_chain_tip = bhh;
         Ok(prior_tip)
     }
@@ -420,6 +440,34 @@ impl BurnDatastore {
         self.open_chain_tip = height_to_id(self.chain_height);
         self.current_chain_tip = self.open_chain_tip;
     }
+
+    /// Overrides the time of the block at `height`, so that tests can control
+    /// what `get-block-info?` and friends return. Returns `false` if there is
+    /// no block at that height.
+    pub fn set_block_time(&mut self, height: u32, time: u64) -> bool {
+        self.store
+            .get_mut(&height_to_id(height))
+            .map(|block| block.burn_block_time = time)
+            .is_some()
+    }
+}
+
+impl HostInterface for BurnDatastore {
+    fn tip_height(&self) -> u32 {
+        self.chain_height
+    }
+
+    fn block_height(&self, id: &StacksBlockId) -> Option<u32> {
+        self.height_at_chain_tip.get(id).copied()
+    }
+
+    fn block_info(&self, height: u32) -> Option<BlockInfo> {
+        self.store.get(&height_to_id(height)).cloned()
+    }
+
+    fn constants(&self) -> &StacksConstants {
+        &self.constants
+    }
 }
 
 impl HeadersDB for BurnDatastore {
@@ -619,6 +667,218 @@ impl BurnStateDB for BurnDatastore {
     }
 }
 
+/// Presents a `HostInterface` as the `HeadersDB` and `BurnStateDB` of a
+/// `ClarityDatabase`. Sortitions are identified like the blocks of
+/// `BurnDatastore`, one per height.
+pub struct HostDatastore<'a> {
+    host: &'a dyn HostInterface,
+}
+
+impl<'a> HostDatastore<'a> {
+    pub fn new(host: &'a dyn HostInterface) -> Self {
+        HostDatastore { host }
+    }
+
+    fn block(&self, id: &StacksBlockId) -> Option<BlockInfo> {
+        self.host
+            .block_height(id)
+            .and_then(|height| self.host.block_info(height))
+    }
+
+    fn sortition_height(&self, sortition_id: &SortitionId) -> Option<u32> {
+        (0..=self.host.tip_height())
+            .find(|height| SortitionId(height_to_hashed_bytes(*height)) == *sortition_id)
+    }
+
+    fn sortition_block(&self, sortition_id: &SortitionId) -> Option<BlockInfo> {
+        self.sortition_height(sortition_id)
+            .and_then(|height| self.host.block_info(height))
+    }
+}
+
+impl HeadersDB for HostDatastore<'_> {
+    fn get_stacks_block_header_hash_for_block(
+        &self,
+        id_bhh: &StacksBlockId,
+        _epoch_id: &StacksEpochId,
+    ) -> Option<BlockHeaderHash> {
+        self.block(id_bhh).map(|block| block.block_header_hash)
+    }
+
+    fn get_burn_header_hash_for_block(
+        &self,
+        id_bhh: &StacksBlockId,
+    ) -> Option<BurnchainHeaderHash> {
+        self.block(id_bhh).map(|block| block.burn_block_header_hash)
+    }
+
+    fn get_consensus_hash_for_block(
+        &self,
+        id_bhh: &StacksBlockId,
+        _epoch_id: &StacksEpochId,
+    ) -> Option<ConsensusHash> {
+        self.block(id_bhh).map(|block| block.consensus_hash)
+    }
+    fn get_vrf_seed_for_block(
+        &self,
+        id_bhh: &StacksBlockId,
+        _epoch_id: &StacksEpochId,
+    ) -> Option<VRFSeed> {
+        self.block(id_bhh).map(|block| block.vrf_seed)
+    }
+    fn get_stacks_block_time_for_block(&self, id_bhh: &StacksBlockId) -> Option<u64> {
+        self.block(id_bhh).map(|block| block.burn_block_time)
+    }
+    fn get_burn_block_time_for_block(
+        &self,
+        id_bhh: &StacksBlockId,
+        _epoch_id: Option<&StacksEpochId>,
+    ) -> Option<u64> {
+        self.block(id_bhh).map(|block| block.burn_block_time)
+    }
+    fn get_burn_block_height_for_block(&self, id_bhh: &StacksBlockId) -> Option<u32> {
+        self.block(id_bhh).map(|block| block.burn_block_height)
+    }
+    fn get_miner_address(
+        &self,
+        id_bhh: &StacksBlockId,
+        _epoch_id: &StacksEpochId,
+    ) -> Option<StacksAddress> {
+        self.block(id_bhh).map(|block| block.miner)
+    }
+    fn get_burnchain_tokens_spent_for_block(
+        &self,
+        id_bhh: &StacksBlockId,
+        _epoch_id: &StacksEpochId,
+    ) -> Option<u128> {
+        self.block(id_bhh)
+            .map(|block| block.burnchain_tokens_spent_for_block)
+    }
+    fn get_burnchain_tokens_spent_for_winning_block(
+        &self,
+        id_bhh: &StacksBlockId,
+        _epoch_id: &StacksEpochId,
+    ) -> Option<u128> {
+        self.block(id_bhh)
+            .map(|block| block.get_burnchain_tokens_spent_for_winning_block)
+    }
+    fn get_tokens_earned_for_block(
+        &self,
+        id_bhh: &StacksBlockId,
+        _epoch_id: &StacksEpochId,
+    ) -> Option<u128> {
+        self.block(id_bhh)
+            .map(|block| block.tokens_earned_for_block)
+    }
+    fn get_stacks_height_for_tenure_height(
+        &self,
+        _tip: &StacksBlockId,
+        tenure_height: u32,
+    ) -> Option<u32> {
+        Some(tenure_height)
+    }
+}
+
+impl BurnStateDB for HostDatastore<'_> {
+    fn get_v1_unlock_height(&self) -> u32 {
+        0
+    }
+
+    fn get_v2_unlock_height(&self) -> u32 {
+        0
+    }
+
+    fn get_pox_3_activation_height(&self) -> u32 {
+        0
+    }
+
+    fn get_tip_burn_block_height(&self) -> Option<u32> {
+        Some(self.host.tip_height())
+    }
+
+    fn get_tip_sortition_id(&self) -> Option<SortitionId> {
+        Some(SortitionId(height_to_hashed_bytes(self.host.tip_height())))
+    }
+
+    fn get_burn_block_height(&self, sortition_id: &SortitionId) -> Option<u32> {
+        self.sortition_block(sortition_id)
+            .map(|block| block.burn_block_height)
+    }
+
+    fn get_burn_start_height(&self) -> u32 {
+        0
+    }
+
+    fn get_v3_unlock_height(&self) -> u32 {
+        0
+    }
+
+    fn get_pox_4_activation_height(&self) -> u32 {
+        0
+    }
+
+    fn get_pox_prepare_length(&self) -> u32 {
+        self.host.constants().pox_prepare_length
+    }
+
+    fn get_pox_reward_cycle_length(&self) -> u32 {
+        self.host.constants().pox_reward_cycle_length
+    }
+
+    fn get_pox_rejection_fraction(&self) -> u64 {
+        self.host.constants().pox_rejection_fraction
+    }
+
+    fn get_burn_header_hash(
+        &self,
+        _height: u32,
+        sortition_id: &SortitionId,
+    ) -> Option<BurnchainHeaderHash> {
+        self.sortition_block(sortition_id)
+            .map(|block| block.burn_block_header_hash)
+    }
+
+    fn get_sortition_id_from_consensus_hash(
+        &self,
+        consensus_hash: &ConsensusHash,
+    ) -> Option<SortitionId> {
+        (0..=self.host.tip_height())
+            .find(|height| {
+                self.host
+                    .block_info(*height)
+                    .is_some_and(|block| block.consensus_hash == *consensus_hash)
+            })
+            .map(|height| SortitionId(height_to_hashed_bytes(height)))
+    }
+
+    fn get_stacks_epoch(&self, _height: u32) -> Option<StacksEpoch> {
+        Some(StacksEpoch {
+            epoch_id: StacksEpochId::Epoch25,
+            start_height: 0,
+            end_height: u64::MAX,
+            block_limit: ExecutionCost::max_value(),
+            network_epoch: PEER_VERSION_EPOCH_2_5,
+        })
+    }
+
+    fn get_stacks_epoch_by_epoch_id(&self, _epoch_id: &StacksEpochId) -> Option<StacksEpoch> {
+        self.get_stacks_epoch(0)
+    }
+
+    fn get_pox_payout_addrs(
+        &self,
+        _height: u32,
+        sortition_id: &SortitionId,
+    ) -> Option<(Vec<TupleData>, u128)> {
+        self.sortition_block(sortition_id)
+            .map(|block| block.pox_payout_addrs)
+    }
+
+    fn get_ast_rules(&self, _height: u32) -> clarity::vm::ast::ASTRules {
+        clarity::vm::ast::ASTRules::PrecheckSize
+    }
+}
+
 impl Datastore {
     pub fn open(_path_str: &str, _miner_tip: Option<&StacksBlockId>) -> Result<Datastore> {
         Ok(Datastore::new())
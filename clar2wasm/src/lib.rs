@@ -1,17 +1,24 @@
 extern crate lazy_static;
 
+use std::collections::{BTreeMap, HashMap};
+
 use clarity::types::StacksEpochId;
 use clarity::vm::analysis::{run_analysis, AnalysisDatabase, ContractAnalysis};
 use clarity::vm::ast::{build_ast_with_diagnostics, ContractAST};
 use clarity::vm::costs::{ExecutionCost, LimitedCostTracker};
 use clarity::vm::diagnostic::Diagnostic;
 use clarity::vm::types::{
-    FixedFunction, ListTypeData, QualifiedContractIdentifier, SequenceSubtype, TypeSignature,
+    FixedFunction, FunctionType, ListTypeData, QualifiedContractIdentifier, SequenceSubtype,
+    TypeSignature,
 };
-use clarity::vm::ClarityVersion;
+use clarity::vm::{ClarityName, ClarityVersion};
+use walrus::ir::{dfs_in_order, Call, Visitor};
 pub use walrus::Module;
-use wasm_generator::{GeneratorError, WasmGenerator};
+use walrus::{FunctionId, FunctionKind, ImportKind};
+use wasm_generator::{GeneratorError, MemoryUsage, WasmGenerator};
+pub use words::ComplexWord;
 
+pub mod cache;
 mod deserialize;
 pub mod initialize;
 pub mod linker;
@@ -42,6 +49,93 @@ pub struct CompileResult {
     pub diagnostics: Vec<Diagnostic>,
     pub module: Module,
     pub contract_analysis: ContractAnalysis,
+    pub memory_usage: MemoryUsage,
+}
+
+/// A function exported by a compiled contract, callable from outside of it.
+#[derive(Debug, Clone, PartialEq)]
+pub struct ExportedFunction<'a> {
+    pub name: &'a ClarityName,
+    pub read_only: bool,
+    pub function_type: &'a FunctionType,
+}
+
+impl CompileResult {
+    /// Returns the public and read-only functions of the contract, which are
+    /// the functions exported by the module, along with their types.
+    pub fn exported_functions(&self) -> Vec<ExportedFunction<'_>> {
+        let public =
+            self.contract_analysis
+                .public_function_types
+                .iter()
+                .map(|(name, function_type)| ExportedFunction {
+                    name,
+                    read_only: false,
+                    function_type,
+                });
+        let read_only =
+            self.contract_analysis
+                .read_only_function_types
+                .iter()
+                .map(|(name, function_type)| ExportedFunction {
+                    name,
+                    read_only: true,
+                    function_type,
+                });
+        public.chain(read_only).collect()
+    }
+
+    /// Counts the calls to each host function in the code generated for the
+    /// contract, keyed by the name of the host function (e.g. `map_get`).
+    ///
+    /// These are call sites, not calls made at runtime: a call in a loop or
+    /// in an untaken branch counts once. This still gives a rough idea of the
+    /// cost of a contract without running it.
+    pub fn host_calls(&self) -> BTreeMap<String, usize> {
+        struct CallCounter<'a> {
+            host_functions: &'a HashMap<FunctionId, &'a str>,
+            counts: BTreeMap<String, usize>,
+        }
+
+        impl<'instr> Visitor<'instr> for CallCounter<'_> {
+            fn visit_call(&mut self, instr: &Call) {
+                if let Some(name) = self.host_functions.get(&instr.func) {
+                    *self.counts.entry(name.to_string()).or_default() += 1;
+                }
+            }
+        }
+
+        let host_functions: HashMap<_, _> = self
+            .module
+            .imports
+            .iter()
+            .filter_map(|import| match import.kind {
+                ImportKind::Function(id) if import.module == "clarity" => {
+                    Some((id, import.name.as_str()))
+                }
+                _ => None,
+            })
+            .collect();
+
+        let mut counter = CallCounter {
+            host_functions: &host_functions,
+            counts: BTreeMap::new(),
+        };
+        // The standard library functions are skipped, they are part of every
+        // module whether the contract uses them or not.
+        for function in self.module.funcs.iter() {
+            let is_stdlib = function
+                .name
+                .as_ref()
+                .is_some_and(|name| name.starts_with("stdlib."));
+            if let FunctionKind::Local(local) = &function.kind {
+                if !is_stdlib {
+                    dfs_in_order(&mut counter, local, local.entry_block());
+                }
+            }
+        }
+        counter.counts
+    }
 }
 
 #[derive(Debug)]
@@ -50,16 +144,76 @@ pub enum CompileError {
         ast: Box<ContractAST>,
         diagnostics: Vec<Diagnostic>,
         cost_tracker: Box<LimitedCostTracker>,
+        /// Stable name of the error that made the compilation fail: the
+        /// variant of the `CheckErrors` or `GeneratorError`, e.g.
+        /// `TypeError`, or `ParseError` for any parse error.
+        code: String,
     },
 }
 
+/// Returns the name of the variant of `error`, e.g. `TypeError` for
+/// `TypeError(IntType, UIntType)`.
+fn error_code(error: &impl std::fmt::Debug) -> String {
+    format!("{error:?}")
+        .split(|c: char| !(c.is_alphanumeric() || c == '_'))
+        .next()
+        .unwrap_or_default()
+        .to_owned()
+}
+
+/// Options controlling the code generation.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct CompileOptions {
+    /// Emit a check after every statement that traps if the stack pointer
+    /// went below the start of the current frame. This is meant to catch
+    /// code generation bugs early and should not be used in production.
+    ///
+    /// Only releasing too much stack space is caught. A statement that
+    /// leaves its locals allocated still passes, as the frame's end is
+    /// not known until the whole function has been generated.
+    pub stack_checks: bool,
+    /// Maximum nesting of the types serialized to or read from memory,
+    /// `MAX_TYPE_DEPTH` if unset. Deeper types produce an error instead of
+    /// exhausting the compiler's stack.
+    pub max_type_depth: Option<u8>,
+    /// Remove the standard library functions that the contract never calls.
+    /// This shrinks the module a lot for small contracts, but the stripped
+    /// functions are no longer exported.
+    pub strip_unused_stdlib: bool,
+    /// Words replacing the standard ones, e.g. to experiment with another
+    /// implementation of a native function. As analysis rejects calls to
+    /// unknown functions, a word is only used if it has the name of a native
+    /// function. The contract's own functions take precedence over these.
+    pub extra_words: &'static [&'static dyn ComplexWord],
+}
+
 pub fn compile(
+    source: &str,
+    contract_id: &QualifiedContractIdentifier,
+    cost_tracker: LimitedCostTracker,
+    clarity_version: ClarityVersion,
+    epoch: StacksEpochId,
+    analysis_db: &mut AnalysisDatabase,
+) -> Result<CompileResult, CompileError> {
+    compile_with_options(
+        source,
+        contract_id,
+        cost_tracker,
+        clarity_version,
+        epoch,
+        analysis_db,
+        CompileOptions::default(),
+    )
+}
+
+pub fn compile_with_options(
     source: &str,
     contract_id: &QualifiedContractIdentifier,
     mut cost_tracker: LimitedCostTracker,
     clarity_version: ClarityVersion,
     epoch: StacksEpochId,
     analysis_db: &mut AnalysisDatabase,
+    options: CompileOptions,
 ) -> Result<CompileResult, CompileError> {
     // Parse the contract
     let (ast, mut diagnostics, success) = build_ast_with_diagnostics(
@@ -75,6 +229,8 @@ pub fn compile(
             ast: Box::new(ast),
             diagnostics,
             cost_tracker: Box::new(cost_tracker),
+            // The diagnostics are all that is left of the parse errors.
+            code: "ParseError".to_owned(),
         });
     }
 
@@ -91,11 +247,14 @@ pub fn compile(
     ) {
         Ok(contract_analysis) => contract_analysis,
         Err((e, cost_track)) => {
-            diagnostics.push(Diagnostic::err(&e.err));
+            // Keep the diagnostic of the error, which has the spans of the
+            // offending expressions.
+            diagnostics.push(e.diagnostic);
             return Err(CompileError::Generic {
                 ast: Box::new(ast),
                 diagnostics,
                 cost_tracker: Box::new(cost_track),
+                code: error_code(&e.err),
             });
         }
     };
@@ -110,6 +269,7 @@ pub fn compile(
         return Err(CompileError::Generic {
             ast: Box::new(ast),
             diagnostics: diagnostics.clone(),
+            code: error_code(&e.err),
             cost_tracker: Box::new(
                 contract_analysis
                     .cost_track
@@ -120,18 +280,22 @@ pub fn compile(
     }
 
     #[allow(clippy::expect_used)]
-    match WasmGenerator::new(contract_analysis.clone()).and_then(WasmGenerator::generate) {
-        Ok(module) => Ok(CompileResult {
+    match WasmGenerator::new_with_options(contract_analysis.clone(), options)
+        .and_then(WasmGenerator::generate_with_memory_usage)
+    {
+        Ok((module, memory_usage)) => Ok(CompileResult {
             ast,
             diagnostics,
             module,
             contract_analysis,
+            memory_usage,
         }),
         Err(e) => {
             diagnostics.push(Diagnostic::err(&e));
             Err(CompileError::Generic {
                 ast: Box::new(ast),
                 diagnostics,
+                code: error_code(&e),
                 cost_tracker: Box::new(
                     contract_analysis
                         .cost_track
@@ -237,8 +401,20 @@ fn typechecker_workaround(ast: &ContractAST, contract_analysis: &mut ContractAna
 }
 
 pub fn compile_contract(contract_analysis: ContractAnalysis) -> Result<Module, GeneratorError> {
-    let generator = WasmGenerator::new(contract_analysis)?;
-    generator.generate()
+    compile_from_analysis(contract_analysis, CompileOptions::default())
+}
+
+/// Generates the module of a contract that has already been analyzed,
+/// without parsing and checking its source again.
+///
+/// The analysis must carry the type map of the contract, with its types
+/// concretized, like the one returned in `CompileResult`. Analyses stored in
+/// the chainstate do not keep it.
+pub fn compile_from_analysis(
+    contract_analysis: ContractAnalysis,
+    options: CompileOptions,
+) -> Result<Module, GeneratorError> {
+    WasmGenerator::new_with_options(contract_analysis, options)?.generate()
 }
 
 mod utils {
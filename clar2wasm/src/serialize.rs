@@ -3,7 +3,7 @@ use clarity::vm::types::serialization::TypePrefix;
 use clarity::vm::types::{
     ListTypeData, SequenceSubtype, StringSubtype, TupleTypeSignature, TypeSignature,
 };
-use walrus::ir::{BinaryOp, IfElse, InstrSeqType, Loop, MemArg, StoreKind};
+use walrus::ir::{BinaryOp, IfElse, InstrSeqType, MemArg, StoreKind};
 use walrus::{InstrSeqBuilder, LocalId, MemoryId, ValType};
 
 use crate::wasm_generator::{clar2wasm_ty, GeneratorError, WasmGenerator};
@@ -515,37 +515,25 @@ impl WasmGenerator {
                 .local_set(write_ptr);
 
             // Loop through elements and serialize
-            let loop_id = {
-                let mut loop_ = size_non_zero.dangling_instr_seq(None);
-                let loop_id = loop_.id();
-
-                self.read_from_memory(&mut loop_, read_ptr, 0, element_ty)?;
-
-                self.serialize_to_memory(&mut loop_, write_ptr, 0, element_ty)?;
-
-                // Adjust pointers (for write_ptr, adjustment is on the stack)
-                loop_
-                    .local_get(write_ptr)
-                    .binop(BinaryOp::I32Add)
-                    .local_set(write_ptr);
-                loop_
-                    .local_get(read_ptr)
-                    .i32_const(element_size)
-                    .binop(BinaryOp::I32Add)
-                    .local_set(read_ptr);
-
-                // we loop while there are bytes to read in the list
-                loop_
-                    .local_get(bytes_length)
-                    .i32_const(element_size)
-                    .binop(BinaryOp::I32Sub)
-                    .local_tee(bytes_length)
-                    .br_if(loop_id);
-
-                loop_id
-            };
-
-            size_non_zero.instr(Loop { seq: loop_id });
+            self.loop_over_memory(
+                &mut size_non_zero,
+                read_ptr,
+                bytes_length,
+                element_size,
+                |generator, loop_| {
+                    generator.read_from_memory(loop_, read_ptr, 0, element_ty)?;
+
+                    generator.serialize_to_memory(loop_, write_ptr, 0, element_ty)?;
+
+                    // Adjust the write pointer (the adjustment is on the stack)
+                    loop_
+                        .local_get(write_ptr)
+                        .binop(BinaryOp::I32Add)
+                        .local_set(write_ptr);
+
+                    Ok(())
+                },
+            )?;
 
             // Push the amount written to the data stack
             size_non_zero
@@ -898,6 +886,18 @@ impl WasmGenerator {
         offset_local: LocalId,
         offset: u32,
         ty: &TypeSignature,
+    ) -> Result<(), GeneratorError> {
+        self.with_nested_type(|generator| {
+            generator.serialize_type_to_memory(builder, offset_local, offset, ty)
+        })
+    }
+
+    fn serialize_type_to_memory(
+        &mut self,
+        builder: &mut InstrSeqBuilder,
+        offset_local: LocalId,
+        offset: u32,
+        ty: &TypeSignature,
     ) -> Result<(), GeneratorError> {
         let memory = self.get_memory()?;
 
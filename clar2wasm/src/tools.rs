@@ -13,16 +13,16 @@ use clarity::vm::ast::build_ast;
 use clarity::vm::contexts::{EventBatch, GlobalContext};
 use clarity::vm::contracts::Contract;
 use clarity::vm::costs::LimitedCostTracker;
-use clarity::vm::database::ClarityDatabase;
+use clarity::vm::database::{BurnStateDB, ClarityDatabase, HeadersDB};
 use clarity::vm::errors::{CheckErrors, Error, WasmError};
 use clarity::vm::events::{SmartContractEventData, StacksTransactionEvent};
 use clarity::vm::types::{PrincipalData, QualifiedContractIdentifier, StandardPrincipalData};
 use clarity::vm::{eval_all, ClarityVersion, ContractContext, ContractName, Value};
 use regex::Regex;
 
-use crate::compile;
-use crate::datastore::{BurnDatastore, Datastore, StacksConstants};
+use crate::datastore::{BurnDatastore, Datastore, HostDatastore, HostInterface, StacksConstants};
 use crate::initialize::initialize_contract;
+use crate::{compile_with_options, CompileOptions};
 
 #[derive(Clone)]
 pub struct TestEnvironment {
@@ -34,6 +34,7 @@ pub struct TestEnvironment {
     cost_tracker: LimitedCostTracker,
     events: Vec<EventBatch>,
     network: Network,
+    pub compile_options: CompileOptions,
 }
 
 impl TestEnvironment {
@@ -69,6 +70,7 @@ impl TestEnvironment {
             cost_tracker,
             events: vec![],
             network: Network::Testnet,
+            compile_options: CompileOptions::default(),
         }
     }
 
@@ -90,6 +92,29 @@ impl TestEnvironment {
         &mut self,
         contract_name: &str,
         snippet: &str,
+    ) -> Result<Option<Value>, Error> {
+        let burn_datastore = self.burn_datastore.clone();
+        self.init_contract(contract_name, snippet, &burn_datastore, &burn_datastore)
+    }
+
+    /// Initializes a contract that reads block and burn block information
+    /// from `host` instead of the environment's chain.
+    pub fn init_contract_with_host(
+        &mut self,
+        contract_name: &str,
+        snippet: &str,
+        host: &dyn HostInterface,
+    ) -> Result<Option<Value>, Error> {
+        let host = HostDatastore::new(host);
+        self.init_contract(contract_name, snippet, &host, &host)
+    }
+
+    fn init_contract(
+        &mut self,
+        contract_name: &str,
+        snippet: &str,
+        headers_db: &dyn HeadersDB,
+        burn_state_db: &dyn BurnStateDB,
     ) -> Result<Option<Value>, Error> {
         let contract_id = QualifiedContractIdentifier::new(
             StandardPrincipalData::transient(),
@@ -100,13 +125,14 @@ impl TestEnvironment {
             .datastore
             .as_analysis_db()
             .execute(|analysis_db| {
-                compile(
+                compile_with_options(
                     snippet,
                     &contract_id,
                     LimitedCostTracker::new_free(),
                     self.version,
                     self.epoch,
                     analysis_db,
+                    self.compile_options,
                 )
                 .map_err(|e| CheckErrors::Expects(format!("Compilation failure {:?}", e)))
             })
@@ -126,11 +152,7 @@ impl TestEnvironment {
         let mut cost_tracker = LimitedCostTracker::new_free();
         std::mem::swap(&mut self.cost_tracker, &mut cost_tracker);
 
-        let conn = ClarityDatabase::new(
-            &mut self.datastore,
-            &self.burn_datastore,
-            &self.burn_datastore,
-        );
+        let conn = ClarityDatabase::new(&mut self.datastore, headers_db, burn_state_db);
 
         let (is_mainnet, chain_id) = match self.network {
             Network::Mainnet => (true, CHAIN_ID_MAINNET),
@@ -192,6 +214,26 @@ impl TestEnvironment {
         self.datastore.advance_chain_tip(count)
     }
 
+    pub fn set_block_time(&mut self, height: u32, time: u64) -> bool {
+        self.burn_datastore.set_block_time(height, time)
+    }
+
+    /// Locks `amount` of the STX of `principal` until the burn block at
+    /// `unlock_height`, as stacking does.
+    pub fn lock_stx(&mut self, principal: &PrincipalData, amount: u128, unlock_height: u64) {
+        let mut conn = ClarityDatabase::new(
+            &mut self.datastore,
+            &self.burn_datastore,
+            &self.burn_datastore,
+        );
+        execute(&mut conn, |database| {
+            let mut snapshot = database.get_stx_balance_snapshot(principal)?;
+            snapshot.lock_tokens_v4(amount, unlock_height)?;
+            snapshot.save()
+        })
+        .expect("Failed to lock STX.");
+    }
+
     pub fn interpret_contract_with_snippet(
         &mut self,
         contract_name: &str,
@@ -11,10 +11,11 @@ use clarity::vm::diagnostic::DiagnosableError;
 use clarity::vm::types::signatures::{CallableSubtype, StringUTF8Length, BUFF_1};
 use clarity::vm::types::{
     ASCIIData, CharType, FixedFunction, FunctionType, ListTypeData, PrincipalData, SequenceData,
-    SequenceSubtype, StringSubtype, TupleTypeSignature, TypeSignature,
+    SequenceSubtype, StringSubtype, TupleTypeSignature, TypeSignature, MAX_TYPE_DEPTH,
 };
 use clarity::vm::variables::NativeVariables;
 use clarity::vm::{functions, variables, ClarityName, SymbolicExpression, SymbolicExpressionType};
+use lazy_static::lazy_static;
 use walrus::ir::{
     BinaryOp, IfElse, InstrSeqId, InstrSeqType, LoadKind, Loop, MemArg, StoreKind, UnaryOp,
 };
@@ -28,11 +29,53 @@ use crate::wasm_utils::{
     check_argument_count, get_type_in_memory_size, get_type_size, signature_from_string,
     ArgumentCountCheck, PRINCIPAL_BYTES_MAX,
 };
-use crate::{check_args, debug_msg, words};
+use crate::{check_args, debug_msg, words, CompileOptions, ComplexWord};
 
-// First free position after data directly defined in standard.wat
+/// The compiled standard library, which every contract module starts from.
+const STANDARD_LIB_WASM: &[u8] = include_bytes!("standard/standard.wasm");
+
+// First free position after data directly defined in standard.wat.
+// The generator computes this from the standard library's data segments,
+// this constant is kept in sync by `end_of_standard_data_is_correct`.
 pub const END_OF_STANDARD_DATA: u32 = 1352;
 
+/// Size of a page of Wasm linear memory.
+const WASM_PAGE_SIZE: u64 = 64 * 1024;
+/// Maximum number of pages of a 32-bit linear memory.
+const MAX_MEMORY_PAGES: u64 = 65536;
+
+lazy_static! {
+    static ref STANDARD_DATA_END: u32 = {
+        #[allow(clippy::expect_used)]
+        let module =
+            Module::from_buffer(STANDARD_LIB_WASM).expect("failed to load standard library");
+        end_of_data(&module)
+    };
+}
+
+/// Returns the first free position after the data directly defined in
+/// standard.wat. The standard library is only parsed on the first call.
+pub fn end_of_standard_data() -> u32 {
+    *STANDARD_DATA_END
+}
+
+/// Returns the first free memory offset after all the active data segments
+/// of `module`.
+fn end_of_data(module: &Module) -> u32 {
+    module
+        .data
+        .iter()
+        .filter_map(|data| match &data.kind {
+            DataKind::Active(ActiveData {
+                location: walrus::ActiveDataLocation::Absolute(offset),
+                ..
+            }) => Some(offset + data.value.len() as u32),
+            _ => None,
+        })
+        .max()
+        .unwrap_or(0)
+}
+
 /// WasmGenerator is a Clarity AST visitor that generates a WebAssembly module
 /// as it traverses the AST.
 pub struct WasmGenerator {
@@ -68,6 +111,31 @@ pub struct WasmGenerator {
     /// to be available on the stack.
     max_work_space: u32,
     local_pool: Rc<RefCell<HashMap<ValType, Vec<LocalId>>>>,
+    /// Size of the call stack frame of each function defined so far.
+    function_frame_sizes: HashMap<String, u32>,
+    /// Whether to emit runtime stack pointer checks (see `CompileOptions`).
+    stack_checks: bool,
+    /// Local holding the stack pointer at the start of the current frame,
+    /// set only when stack checks are enabled.
+    frame_start: Option<LocalId>,
+    /// Maximum nesting of the types handled by `serialize_to_memory` and
+    /// `read_from_memory` (see `CompileOptions`).
+    max_type_depth: u8,
+    /// Current nesting of the type being serialized or read from memory.
+    type_depth: u8,
+    /// Whether to remove the unused standard library functions (see `CompileOptions`).
+    strip_unused_stdlib: bool,
+    /// Words added to the standard ones (see `CompileOptions`).
+    extra_words: &'static [&'static dyn ComplexWord],
+}
+
+/// Memory needed by a compiled contract, besides the standard library.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct MemoryUsage {
+    /// Size of the literal memory, in bytes.
+    pub literal_memory: u32,
+    /// Size of the call stack frame of each defined function, in bytes.
+    pub frame_sizes: HashMap<String, u32>,
 }
 
 #[derive(Debug, Clone, Default)]
@@ -114,6 +182,8 @@ pub enum LiteralMemoryEntry {
 #[derive(Debug)]
 pub enum GeneratorError {
     NotImplemented,
+    /// A native function that has no word to generate its code yet.
+    NotImplementedFunction(ClarityName),
     InternalError(String),
     TypeError(String),
     ArgumentCountMismatch,
@@ -129,6 +199,9 @@ impl DiagnosableError for GeneratorError {
     fn message(&self) -> String {
         match self {
             GeneratorError::NotImplemented => "Not implemented".to_string(),
+            GeneratorError::NotImplementedFunction(name) => {
+                format!("Not implemented: {}", name)
+            }
             GeneratorError::InternalError(msg) => format!("Internal error: {}", msg),
             GeneratorError::TypeError(msg) => format!("Type error: {}", msg),
             GeneratorError::ArgumentCountMismatch => "Argument count mismatch".to_string(),
@@ -304,18 +377,26 @@ impl Deref for BorrowedLocal {
 
 impl WasmGenerator {
     pub fn new(contract_analysis: ContractAnalysis) -> Result<WasmGenerator, GeneratorError> {
-        let standard_lib_wasm: &[u8] = include_bytes!("standard/standard.wasm");
+        Self::new_with_options(contract_analysis, CompileOptions::default())
+    }
 
-        let module = Module::from_buffer(standard_lib_wasm).map_err(|_err| {
+    pub fn new_with_options(
+        contract_analysis: ContractAnalysis,
+        options: CompileOptions,
+    ) -> Result<WasmGenerator, GeneratorError> {
+        let module = Module::from_buffer(STANDARD_LIB_WASM).map_err(|_err| {
             GeneratorError::InternalError("failed to load standard library".to_owned())
         })?;
         // Get the stack-pointer global ID
         let global_id = get_global(&module, "stack-pointer")?;
 
+        // Literals are written right after the standard library's data.
+        let literal_memory_end = end_of_data(&module);
+
         Ok(WasmGenerator {
             contract_analysis,
             module,
-            literal_memory_end: END_OF_STANDARD_DATA,
+            literal_memory_end,
             stack_pointer: global_id,
             literal_memory_offset: HashMap::new(),
             constants: HashMap::new(),
@@ -328,6 +409,13 @@ impl WasmGenerator {
             maps_types: HashMap::new(),
             local_pool: Rc::new(RefCell::new(HashMap::new())),
             nft_types: HashMap::new(),
+            function_frame_sizes: HashMap::new(),
+            stack_checks: options.stack_checks,
+            frame_start: None,
+            max_type_depth: options.max_type_depth.unwrap_or(MAX_TYPE_DEPTH),
+            type_depth: 0,
+            strip_unused_stdlib: options.strip_unused_stdlib,
+            extra_words: options.extra_words,
         })
     }
 
@@ -339,17 +427,46 @@ impl WasmGenerator {
             .next()
             .ok_or_else(|| GeneratorError::InternalError("No Memory found".to_owned()))?;
 
-        let total_memory_bytes =
-            self.literal_memory_end + (self.frame_size as u32) + self.max_work_space;
-        let pages_required = total_memory_bytes / (64 * 1024);
-        let remainder = total_memory_bytes % (64 * 1024);
+        let total_memory_bytes = u64::from(self.literal_memory_end)
+            + u64::from(self.frame_size as u32)
+            + u64::from(self.max_work_space);
+        let remainder = total_memory_bytes % WASM_PAGE_SIZE;
+        let pages_required = total_memory_bytes / WASM_PAGE_SIZE + (remainder > 0) as u64;
+        if pages_required > MAX_MEMORY_PAGES {
+            return Err(GeneratorError::InternalError(format!(
+                "contract requires {total_memory_bytes} bytes of memory, \
+                 more than the {MAX_MEMORY_PAGES} pages available"
+            )));
+        }
 
-        memory.initial = pages_required + (remainder > 0) as u32;
+        memory.initial = pages_required as u32;
 
         Ok(())
     }
 
-    pub fn generate(mut self) -> Result<Module, GeneratorError> {
+    /// Reserves `len` bytes at the end of the literal memory and returns their offset.
+    fn reserve_literal_memory(&mut self, len: usize) -> Result<u32, GeneratorError> {
+        let offset = self.literal_memory_end;
+        self.literal_memory_end = u32::try_from(len)
+            .ok()
+            .and_then(|len| offset.checked_add(len))
+            .ok_or_else(|| {
+                GeneratorError::InternalError(
+                    "literal data does not fit in the linear memory".to_owned(),
+                )
+            })?;
+        Ok(offset)
+    }
+
+    pub fn generate(self) -> Result<Module, GeneratorError> {
+        self.generate_with_memory_usage().map(|(module, _)| module)
+    }
+
+    /// Generates the module, along with the memory it needs besides the
+    /// standard library.
+    pub fn generate_with_memory_usage(mut self) -> Result<(Module, MemoryUsage), GeneratorError> {
+        // Literals are added to the module's data, so get its size beforehand.
+        let standard_data_end = end_of_data(&self.module);
         let expressions = std::mem::take(&mut self.contract_analysis.expressions);
 
         // Get the type of the last top-level expression with a return value
@@ -362,6 +479,15 @@ impl WasmGenerator {
 
         let mut current_function = FunctionBuilder::new(&mut self.module.types, &[], &return_ty);
 
+        if self.stack_checks {
+            let frame_start = self.module.locals.add(ValType::I32);
+            current_function
+                .func_body()
+                .global_get(self.stack_pointer)
+                .local_set(frame_start);
+            self.frame_start = Some(frame_start);
+        }
+
         if !expressions.is_empty() {
             self.traverse_statement_list(&mut current_function.func_body(), &expressions)?;
         }
@@ -379,7 +505,36 @@ impl WasmGenerator {
             walrus::InitExpr::Value(walrus::ir::Value::I32(self.literal_memory_end as i32)),
         );
 
-        Ok(self.module)
+        if self.strip_unused_stdlib {
+            self.remove_unused_stdlib();
+        }
+
+        let memory_usage = MemoryUsage {
+            literal_memory: self.literal_memory_end - standard_data_end,
+            frame_sizes: self.function_frame_sizes,
+        };
+
+        Ok((self.module, memory_usage))
+    }
+
+    /// Removes the standard library functions that the contract does not call.
+    /// The standard module exports all of them, which keeps them alive, but
+    /// the host never calls them through these exports.
+    fn remove_unused_stdlib(&mut self) {
+        let stdlib_exports: Vec<_> = self
+            .module
+            .exports
+            .iter()
+            .filter(|export| {
+                export.name.starts_with("stdlib.")
+                    && matches!(export.item, walrus::ExportItem::Function(_))
+            })
+            .map(|export| export.id())
+            .collect();
+        for id in stdlib_exports {
+            self.module.exports.delete(id);
+        }
+        walrus::passes::gc::run(&mut self.module);
     }
 
     pub fn get_memory(&self) -> Result<MemoryId, GeneratorError> {
@@ -403,6 +558,17 @@ impl WasmGenerator {
             SymbolicExpressionType::LiteralValue(value) => {
                 self.visit_literal_value(builder, expr, value)
             }
+            // Traits only name types, and the type checker rejects them in
+            // value position. Words like `use-trait` read them directly.
+            SymbolicExpressionType::Field(trait_identifier) => {
+                Err(GeneratorError::TypeError(format!(
+                    "trait {}.{} cannot be used as a value",
+                    trait_identifier.contract_identifier, trait_identifier.name
+                )))
+            }
+            SymbolicExpressionType::TraitReference(name, _) => Err(GeneratorError::TypeError(
+                format!("trait reference <{name}> cannot be used as a value"),
+            )),
             _ => Ok(()),
         }
     }
@@ -440,10 +606,14 @@ impl WasmGenerator {
                     Ok((arg_types?, return_type?))
                 };
 
+                // Extra words come first, so that they can stand in for the
+                // standard word of a native function.
                 // Complex words handle their own argument traversal, and have priority
                 // since we need to have a slight overlap for the words `and` and `or`
                 // which exist in both complex and simple forms
-                if let Some(word) = words::lookup_complex(function_name) {
+                if let Some(word) = self.lookup_extra_word(function_name) {
+                    word.traverse(self, builder, expr, args)?;
+                } else if let Some(word) = words::lookup_complex(function_name) {
                     word.traverse(self, builder, expr, args)?;
                 } else if let Some(simpleword) = words::lookup_simple(function_name) {
                     let (arg_types, return_type) = get_types()?;
@@ -481,7 +651,7 @@ impl WasmGenerator {
 
                     // first argument is traversed outside loop
                 } else {
-                    self.traverse_call_user_defined(builder, expr, function_name, args)?;
+                    self.traverse_call_without_word(builder, expr, function_name, args)?;
                 }
             }
             _ => return Err(GeneratorError::InternalError("Invalid list".into())),
@@ -489,6 +659,31 @@ impl WasmGenerator {
         Ok(())
     }
 
+    /// Generates a call to a function no word implements. That is either a
+    /// function defined by the contract or a native function that is not
+    /// supported yet.
+    fn traverse_call_without_word(
+        &mut self,
+        builder: &mut InstrSeqBuilder,
+        expr: &SymbolicExpression,
+        name: &ClarityName,
+        args: &[SymbolicExpression],
+    ) -> Result<(), GeneratorError> {
+        if functions::lookup_reserved_functions(
+            name.as_str(),
+            &self.contract_analysis.clarity_version,
+        )
+        .is_some()
+        {
+            // A native function without a word would otherwise be compiled as
+            // a call to a missing user-defined function. This is defensive:
+            // every native function currently has a word, so `compile` never
+            // gets here.
+            return Err(GeneratorError::NotImplementedFunction(name.clone()));
+        }
+        self.traverse_call_user_defined(builder, expr, name, args)
+    }
+
     pub fn traverse_define_function(
         &mut self,
         builder: &mut InstrSeqBuilder,
@@ -576,6 +771,12 @@ impl WasmGenerator {
         // restore after.
         let top_level_locals = std::mem::replace(&mut self.bindings, bindings);
 
+        // Statements in the body are checked against this function's frame.
+        let top_level_frame_start = self.frame_start;
+        if self.stack_checks {
+            self.frame_start = Some(frame_pointer);
+        }
+
         let mut block = func_body.dangling_instr_seq(InstrSeqType::new(
             &mut self.module.types,
             &[],
@@ -586,8 +787,13 @@ impl WasmGenerator {
         self.early_return_block_id = Some(block_id);
 
         // Traverse the body of the function
+        let frame_size_before = self.frame_size;
         self.set_expr_type(body, function_type.returns.clone())?;
         self.traverse_expr(&mut block, body)?;
+        self.function_frame_sizes.insert(
+            name.to_string(),
+            (self.frame_size - frame_size_before) as u32,
+        );
 
         // If the same arg name is used multiple times, the interpreter throws an
         // `Unchecked` error at runtime, so we do the same here
@@ -615,13 +821,17 @@ impl WasmGenerator {
         func_body.instr(walrus::ir::Block { seq: block_id });
 
         // Function postlude
+        if self.stack_checks {
+            self.emit_stack_check(&mut func_body, frame_pointer);
+        }
         // Restore the initial stack pointer.
         func_body
             .local_get(frame_pointer)
             .global_set(self.stack_pointer);
 
-        // Restore the top-level locals map.
+        // Restore the top-level locals map and frame.
         self.bindings = top_level_locals;
+        self.frame_start = top_level_frame_start;
 
         // Reset the return type and early block to None
         self.current_function_type = None;
@@ -832,8 +1042,8 @@ impl WasmGenerator {
             }
         };
         let memory = self.get_memory()?;
-        let offset = self.literal_memory_end;
         let len = data.len() as u32;
+        let offset = self.reserve_literal_memory(data.len())?;
         self.module.data.add(
             DataKind::Active(ActiveData {
                 memory,
@@ -841,7 +1051,6 @@ impl WasmGenerator {
             }),
             data,
         );
-        self.literal_memory_end += len;
 
         // Save the offset in the literal memory for this string
         self.literal_memory_offset.insert(entry, offset);
@@ -859,8 +1068,8 @@ impl WasmGenerator {
         }
 
         let memory = self.get_memory()?;
-        let offset = self.literal_memory_end;
         let len = name.len() as u32;
+        let offset = self.reserve_literal_memory(name.len())?;
         self.module.data.add(
             DataKind::Active(ActiveData {
                 memory,
@@ -868,7 +1077,6 @@ impl WasmGenerator {
             }),
             name.as_bytes().to_vec(),
         );
-        self.literal_memory_end += name.len() as u32;
 
         // Save the offset in the literal memory for this identifier
         self.literal_memory_offset.insert(entry, offset);
@@ -883,8 +1091,8 @@ impl WasmGenerator {
         }
 
         let memory = self.get_memory()?;
-        let offset = self.literal_memory_end;
         let len = bytes.len() as u32;
+        let offset = self.reserve_literal_memory(bytes.len())?;
         self.module.data.add(
             DataKind::Active(ActiveData {
                 memory,
@@ -892,7 +1100,6 @@ impl WasmGenerator {
             }),
             bytes.to_vec(),
         );
-        self.literal_memory_end += len;
 
         self.literal_memory_offset.insert(entry, offset);
 
@@ -961,16 +1168,15 @@ impl WasmGenerator {
             }
         };
         let memory = self.get_memory()?;
-        let offset = self.literal_memory_end;
         let len = data.len() as u32;
+        let offset = self.reserve_literal_memory(data.len())?;
         self.module.data.add(
             DataKind::Active(ActiveData {
                 memory,
                 location: walrus::ActiveDataLocation::Absolute(offset),
             }),
-            data.clone(),
+            data,
         );
-        self.literal_memory_end += data.len() as u32;
 
         Ok((offset, len))
     }
@@ -1043,6 +1249,50 @@ impl WasmGenerator {
         (offset, size)
     }
 
+    /// Emits a counted loop over the memory region starting at `read_ptr` and
+    /// spanning `bytes_length` bytes, in steps of `element_size` bytes.
+    /// `body` generates the code executed for each element, with `read_ptr`
+    /// pointing to the current element. After each iteration, `read_ptr` is
+    /// advanced and `bytes_length` is decremented by `element_size`.
+    ///
+    /// The generated loop executes at least once, so the caller must ensure
+    /// that `bytes_length` is non-zero.
+    pub(crate) fn loop_over_memory<F>(
+        &mut self,
+        builder: &mut InstrSeqBuilder,
+        read_ptr: LocalId,
+        bytes_length: LocalId,
+        element_size: i32,
+        body: F,
+    ) -> Result<(), GeneratorError>
+    where
+        F: FnOnce(&mut Self, &mut InstrSeqBuilder) -> Result<(), GeneratorError>,
+    {
+        let mut loop_ = builder.dangling_instr_seq(None);
+        let loop_id = loop_.id();
+
+        body(self, &mut loop_)?;
+
+        // Move the read pointer to the next element
+        loop_
+            .local_get(read_ptr)
+            .i32_const(element_size)
+            .binop(BinaryOp::I32Add)
+            .local_set(read_ptr);
+
+        // we loop while there are bytes to read
+        loop_
+            .local_get(bytes_length)
+            .i32_const(element_size)
+            .binop(BinaryOp::I32Sub)
+            .local_tee(bytes_length)
+            .br_if(loop_id);
+
+        builder.instr(Loop { seq: loop_id });
+
+        Ok(())
+    }
+
     pub(crate) fn borrow_local(&mut self, ty: ValType) -> BorrowedLocal {
         let reuse = (*self.local_pool)
             .borrow_mut()
@@ -1232,6 +1482,37 @@ impl WasmGenerator {
         offset: LocalId,
         literal_offset: u32,
         ty: &TypeSignature,
+    ) -> Result<i32, GeneratorError> {
+        self.with_nested_type(|generator| {
+            generator.read_type_from_memory(builder, offset, literal_offset, ty)
+        })
+    }
+
+    /// Runs `f` one level deeper in the type being handled, failing if that
+    /// goes beyond `max_type_depth`. Recursive code generation over types goes
+    /// through this so that deep types cannot overflow the compiler's stack.
+    pub(crate) fn with_nested_type<T>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<T, GeneratorError>,
+    ) -> Result<T, GeneratorError> {
+        if self.type_depth >= self.max_type_depth {
+            return Err(GeneratorError::TypeError(format!(
+                "type nesting exceeds the maximum depth of {}",
+                self.max_type_depth
+            )));
+        }
+        self.type_depth += 1;
+        let result = f(self);
+        self.type_depth -= 1;
+        result
+    }
+
+    fn read_type_from_memory(
+        &mut self,
+        builder: &mut InstrSeqBuilder,
+        offset: LocalId,
+        literal_offset: u32,
+        ty: &TypeSignature,
     ) -> Result<i32, GeneratorError> {
         let memory = self
             .module
@@ -1377,11 +1658,32 @@ impl WasmGenerator {
                 last_ty = Some(ty.clone());
             }
             self.traverse_expr(builder, stmt)?;
+
+            if let Some(frame_start) = self.frame_start {
+                self.emit_stack_check(builder, frame_start);
+            }
         }
 
         Ok(())
     }
 
+    /// Emits a check that traps if the stack pointer is below `frame_start`,
+    /// which means that the code before it released more stack space than it
+    /// allocated.
+    fn emit_stack_check(&self, builder: &mut InstrSeqBuilder, frame_start: LocalId) {
+        builder
+            .global_get(self.stack_pointer)
+            .local_get(frame_start)
+            .binop(BinaryOp::I32LtU)
+            .if_else(
+                None,
+                |then| {
+                    then.unreachable();
+                },
+                |_| {},
+            );
+    }
+
     /// If `name` is a reserved variable, push its value onto the data stack.
     pub fn lookup_reserved_variable(
         &mut self,
@@ -1592,7 +1894,7 @@ impl WasmGenerator {
     fn visit_literal_value(
         &mut self,
         builder: &mut InstrSeqBuilder,
-        _expr: &SymbolicExpression,
+        expr: &SymbolicExpression,
         value: &clarity::vm::Value,
     ) -> Result<(), GeneratorError> {
         match value {
@@ -1619,10 +1921,22 @@ impl WasmGenerator {
                 builder.i32_const(len as i32);
                 Ok(())
             }
-            clarity::vm::Value::Bool(_)
-            | clarity::vm::Value::Tuple(_)
-            | clarity::vm::Value::Optional(_)
-            | clarity::vm::Value::Response(_)
+            clarity::vm::Value::Bool(b) => {
+                builder.i32_const(*b as i32);
+                Ok(())
+            }
+            clarity::vm::Value::Optional(_) | clarity::vm::Value::Response(_) => {
+                // The value alone does not give the type of a `none` or of the
+                // unused arm of a response, so use the type of the expression.
+                let ty = self
+                    .get_expr_type(expr)
+                    .ok_or_else(|| {
+                        GeneratorError::TypeError("literal value must be typed".to_owned())
+                    })?
+                    .clone();
+                self.visit_wrapped_literal_value(builder, expr, &ty, value)
+            }
+            clarity::vm::Value::Tuple(_)
             | clarity::vm::Value::CallableContract(_)
             | clarity::vm::Value::Sequence(_) => Err(GeneratorError::TypeError(format!(
                 "Not a valid literal type: {:?}",
@@ -1631,6 +1945,51 @@ impl WasmGenerator {
         }
     }
 
+    /// Pushes a literal value of type `ty`, which may be wrapped in
+    /// optionals and responses, onto the stack: the indicator, followed by
+    /// the inner value or placeholders for it.
+    fn visit_wrapped_literal_value(
+        &mut self,
+        builder: &mut InstrSeqBuilder,
+        expr: &SymbolicExpression,
+        ty: &TypeSignature,
+        value: &clarity::vm::Value,
+    ) -> Result<(), GeneratorError> {
+        match (value, ty) {
+            (clarity::vm::Value::Optional(optional), TypeSignature::OptionalType(some_ty)) => {
+                match &optional.data {
+                    Some(inner) => {
+                        builder.i32_const(1);
+                        self.visit_wrapped_literal_value(builder, expr, some_ty, inner)
+                    }
+                    None => {
+                        builder.i32_const(0);
+                        add_placeholder_for_clarity_type(builder, some_ty);
+                        Ok(())
+                    }
+                }
+            }
+            (clarity::vm::Value::Response(response), TypeSignature::ResponseType(types)) => {
+                let (ok_ty, err_ty) = &**types;
+                builder.i32_const(response.committed as i32);
+                if response.committed {
+                    self.visit_wrapped_literal_value(builder, expr, ok_ty, &response.data)?;
+                    add_placeholder_for_clarity_type(builder, err_ty);
+                } else {
+                    add_placeholder_for_clarity_type(builder, ok_ty);
+                    self.visit_wrapped_literal_value(builder, expr, err_ty, &response.data)?;
+                }
+                Ok(())
+            }
+            (clarity::vm::Value::Optional(_) | clarity::vm::Value::Response(_), _) => {
+                Err(GeneratorError::TypeError(format!(
+                    "literal value {value:?} does not match its type {ty:?}"
+                )))
+            }
+            _ => self.visit_literal_value(builder, expr, value),
+        }
+    }
+
     fn visit_atom(
         &mut self,
         builder: &mut InstrSeqBuilder,
@@ -2099,6 +2458,18 @@ impl WasmGenerator {
             .call(self.func_by_name("log"));
     }
 
+    /// Finds a word from `CompileOptions::extra_words`. Those replace the
+    /// standard words, but never shadow a function defined by the contract.
+    fn lookup_extra_word(&self, name: &ClarityName) -> Option<&'static dyn ComplexWord> {
+        if self.get_function_type(name.as_str()).is_some() {
+            return None;
+        }
+        self.extra_words
+            .iter()
+            .find(|word| word.name() == *name)
+            .copied()
+    }
+
     pub(crate) fn is_reserved_name(&self, name: &ClarityName) -> bool {
         let version = self.contract_analysis.clarity_version;
 
@@ -2217,16 +2588,45 @@ mod tests {
     use clarity::vm::database::MemoryBackingStore;
     use clarity::vm::errors::{CheckErrors, Error};
     use clarity::vm::types::{QualifiedContractIdentifier, StandardPrincipalData, TupleData};
-    use clarity::vm::{ClarityVersion, Value};
-    use walrus::Module;
+    use clarity::vm::{ClarityName, ClarityVersion, SymbolicExpression, Value};
+    use walrus::{ActiveData, DataKind, Module};
 
     // Tests that don't relate to specific words
     use crate::{
-        compile,
-        tools::{crosscheck, evaluate},
-        wasm_generator::END_OF_STANDARD_DATA,
+        compile, compile_from_analysis, compile_with_options,
+        tools::{crosscheck, evaluate, TestEnvironment},
+        wasm_generator::{
+            end_of_data, end_of_standard_data, get_global, GeneratorError, WasmGenerator,
+            END_OF_STANDARD_DATA,
+        },
+        CompileError, CompileOptions, CompileResult,
     };
 
+    /// Compiles `snippet` as a Clarity 2 contract in epoch 2.5.
+    fn compile_snippet(
+        snippet: &str,
+        options: CompileOptions,
+    ) -> Result<CompileResult, CompileError> {
+        compile_with_options(
+            snippet,
+            &QualifiedContractIdentifier::new(StandardPrincipalData::transient(), ("tmp").into()),
+            LimitedCostTracker::new_free(),
+            ClarityVersion::Clarity2,
+            StacksEpochId::Epoch25,
+            &mut AnalysisDatabase::new(&mut MemoryBackingStore::new()),
+            options,
+        )
+    }
+
+    /// A generator for a trivial contract, to drive the code generation
+    /// directly.
+    fn test_generator(options: CompileOptions) -> WasmGenerator {
+        let contract_analysis = compile_snippet("(define-read-only (get-one) u1)", options)
+            .unwrap()
+            .contract_analysis;
+        WasmGenerator::new_with_options(contract_analysis, options).unwrap()
+    }
+
     #[test]
     fn is_in_regtest() {
         crosscheck(
@@ -2287,6 +2687,97 @@ mod tests {
         );
     }
 
+    #[test]
+    fn literals_larger_than_a_page_grow_memory() {
+        // Two distinct 40KiB buffers do not fit in a single 64KiB page.
+        let a = "aa".repeat(40 * 1024);
+        let b = "bb".repeat(40 * 1024);
+        let snippet = format!("(is-eq 0x{a} 0x{b})");
+
+        let result = compile_snippet(&snippet, CompileOptions::default()).unwrap();
+
+        assert!(result.memory_usage.literal_memory > 64 * 1024);
+        let pages = result.module.memories.iter().next().unwrap().initial;
+        assert!(pages as u64 * 64 * 1024 > result.memory_usage.literal_memory as u64);
+
+        crosscheck(&snippet, Ok(Some(Value::Bool(false))));
+    }
+
+    #[test]
+    fn drop_value_balances_the_stack() {
+        use clarity::vm::types::signatures::BUFF_1;
+        use clarity::vm::types::{
+            BufferLength, SequenceSubtype, StringSubtype, TupleTypeSignature, TypeSignature,
+        };
+        use walrus::{FunctionBuilder, ValType};
+
+        use crate::wasm_generator::{clar2wasm_ty, drop_value};
+
+        let string_ascii = TypeSignature::SequenceType(SequenceSubtype::StringType(
+            StringSubtype::ASCII(BufferLength::try_from(3u32).unwrap()),
+        ));
+        let tuple = TypeSignature::TupleType(
+            TupleTypeSignature::try_from(vec![
+                ("a".into(), TypeSignature::IntType),
+                ("b".into(), BUFF_1.clone()),
+                (
+                    "c".into(),
+                    TypeSignature::new_option(string_ascii.clone()).unwrap(),
+                ),
+            ])
+            .unwrap(),
+        );
+        let types = [
+            TypeSignature::NoType,
+            TypeSignature::IntType,
+            TypeSignature::UIntType,
+            TypeSignature::BoolType,
+            TypeSignature::PrincipalType,
+            BUFF_1.clone(),
+            string_ascii,
+            TypeSignature::new_option(TypeSignature::IntType).unwrap(),
+            TypeSignature::new_response(TypeSignature::IntType, BUFF_1.clone()).unwrap(),
+            TypeSignature::list_of(TypeSignature::IntType, 5).unwrap(),
+            TypeSignature::list_of(tuple.clone(), 5).unwrap(),
+            TypeSignature::new_response(tuple.clone(), TypeSignature::NoType).unwrap(),
+            tuple,
+        ];
+
+        for ty in types {
+            // A function taking the value as parameters, pushing it and
+            // dropping it, only validates if `drop_value` removes every slot.
+            let mut module = Module::default();
+            let params = clar2wasm_ty(&ty);
+            let locals: Vec<_> = params.iter().map(|t| module.locals.add(*t)).collect();
+            let mut function = FunctionBuilder::new(&mut module.types, &params, &[ValType::I32]);
+            let mut body = function.func_body();
+            for local in &locals {
+                body.local_get(*local);
+            }
+            drop_value(&mut body, &ty);
+            body.i32_const(1);
+            let id = function.finish(locals, &mut module.funcs);
+            module.exports.add("f", id);
+
+            assert!(
+                wasmparser::validate(&module.emit_wasm()).is_ok(),
+                "stack is not balanced after dropping {ty}"
+            );
+        }
+    }
+
+    #[test]
+    fn buffer_literal_larger_than_a_page() {
+        // A single 70000 bytes literal, ending with a distinct byte.
+        let buff = format!("{}ff", "ab".repeat(69999));
+        crosscheck(
+            &format!(
+                "(let ((b 0x{buff})) {{ len: (len b), first: (element-at? b u0), last: (element-at? b u69999) }})"
+            ),
+            evaluate("{ len: u70000, first: (some 0xab), last: (some 0xff) }"),
+        );
+    }
+
     #[test]
     fn end_of_standard_data_is_correct() {
         const STANDARD_LIB_PATH: &str =
@@ -2295,7 +2786,719 @@ mod tests {
         let module = Module::from_buffer(&standard_lib_wasm).unwrap();
         let initial_data_size: usize = module.data.iter().map(|d| d.value.len()).sum();
 
-        assert!((initial_data_size as u32) == END_OF_STANDARD_DATA);
+        assert_eq!(initial_data_size as u32, end_of_standard_data());
+        assert_eq!(end_of_data(&module), END_OF_STANDARD_DATA);
+        assert_eq!(end_of_standard_data(), END_OF_STANDARD_DATA);
+    }
+
+    #[test]
+    fn literal_memory_starts_after_standard_data() {
+        let module = compile_snippet(
+            r#"(define-read-only (greet) "a literal only this contract has")"#,
+            CompileOptions::default(),
+        )
+        .unwrap()
+        .module;
+
+        let offset = module
+            .data
+            .iter()
+            .find_map(|data| match &data.kind {
+                DataKind::Active(ActiveData {
+                    location: walrus::ActiveDataLocation::Absolute(offset),
+                    ..
+                }) if data.value == b"a literal only this contract has" => Some(*offset),
+                _ => None,
+            })
+            .expect("the literal should be in a data segment");
+        assert!(offset >= end_of_standard_data());
+    }
+
+    #[test]
+    fn memory_usage_reports_frame_sizes() {
+        let snippet = "
+            (define-data-var x int 0)
+            (define-data-var y int 0)
+            (define-data-var z int 0)
+            (define-private (store (a int) (b int) (c int))
+                (begin (var-set x a) (var-set y b) (var-set z c)))
+            (define-private (noop) true)
+        ";
+        let memory_usage = compile_snippet(snippet, CompileOptions::default())
+            .unwrap()
+            .memory_usage;
+
+        // Each `var-set` of an int reserves 16 bytes on the call stack.
+        assert_eq!(memory_usage.frame_sizes.get("store"), Some(&48));
+        assert_eq!(memory_usage.frame_sizes.get("noop"), Some(&0));
+        // The names of the variables and functions are stored as literals.
+        assert!(memory_usage.literal_memory >= "xyzstorenoop".len() as u32);
+    }
+
+    #[test]
+    fn native_function_unavailable_in_target_version() {
+        // `to-consensus-buff?` was introduced with Clarity 2 in epoch 2.1.
+        let result = compile(
+            "(define-read-only (to-buff) (to-consensus-buff? u1))",
+            &QualifiedContractIdentifier::new(StandardPrincipalData::transient(), ("tmp").into()),
+            LimitedCostTracker::new_free(),
+            ClarityVersion::Clarity1,
+            StacksEpochId::Epoch2_05,
+            &mut AnalysisDatabase::new(&mut MemoryBackingStore::new()),
+        );
+
+        let Err(CompileError::Generic { diagnostics, .. }) = result else {
+            panic!("to-consensus-buff? should not compile in Clarity 1");
+        };
+        assert!(diagnostics
+            .iter()
+            .any(|d| d.message.contains("to-consensus-buff?")));
+    }
+
+    #[test]
+    fn type_nesting_beyond_max_depth_is_an_error() {
+        let snippet = "(to-consensus-buff? (some (some (some 1))))";
+        let compile_with_max_depth = |max_type_depth| {
+            compile_snippet(
+                snippet,
+                CompileOptions {
+                    max_type_depth,
+                    ..Default::default()
+                },
+            )
+        };
+
+        assert!(compile_with_max_depth(None).is_ok());
+        assert!(compile_with_max_depth(Some(4)).is_ok());
+
+        let Err(CompileError::Generic {
+            diagnostics, code, ..
+        }) = compile_with_max_depth(Some(3))
+        else {
+            panic!("serializing a type of depth 4 should fail with a maximum depth of 3");
+        };
+        assert_eq!(code, "TypeError");
+        assert!(diagnostics
+            .iter()
+            .any(|d| d.message == "Type error: type nesting exceeds the maximum depth of 3"));
+    }
+
+    #[test]
+    fn compile_from_existing_analysis() {
+        let contract_analysis = compile_snippet(
+            "(define-read-only (get-one) u1) (define-public (add (a int)) (ok (+ a 1)))",
+            CompileOptions::default(),
+        )
+        .unwrap()
+        .contract_analysis;
+
+        let module = compile_from_analysis(contract_analysis, CompileOptions::default()).unwrap();
+
+        for name in ["get-one", "add", ".top-level"] {
+            assert!(
+                module.exports.iter().any(|export| export.name == name),
+                "missing export {name}"
+            );
+        }
+    }
+
+    #[test]
+    fn compilation_is_deterministic() {
+        let snippet = r#"
+            (define-constant owner tx-sender)
+            (define-constant greetings (list "hello" "world"))
+            (define-data-var counter uint u0)
+            (define-map names principal (string-utf8 20))
+            (define-non-fungible-token ticket { event: uint, seat: uint })
+            (define-public (greet (name (string-utf8 20)))
+                (begin
+                    (map-set names owner name)
+                    (var-set counter (+ (var-get counter) u1))
+                    (print { name: name, count: (var-get counter), greetings: greetings })
+                    (ok 0x0102030405)))
+        "#;
+        let emit = || {
+            compile_snippet(snippet, CompileOptions::default())
+                .unwrap()
+                .module
+                .emit_wasm()
+        };
+
+        assert!(
+            emit() == emit(),
+            "compiling twice produced different modules"
+        );
+    }
+
+    #[test]
+    fn compile_error_diagnostics_have_spans() {
+        let result = compile_snippet(
+            "(define-read-only (get-one)\n  (+ 1 2)",
+            CompileOptions::default(),
+        );
+
+        let Err(CompileError::Generic {
+            diagnostics, code, ..
+        }) = result
+        else {
+            panic!("an unclosed list should not compile");
+        };
+        assert_eq!(code, "ParseError");
+        let diagnostic = diagnostics
+            .iter()
+            .find(|d| matches!(d.level, clarity::vm::diagnostic::Level::Error))
+            .expect("expected an error diagnostic");
+        assert!(!diagnostic.spans.is_empty());
+        assert!(diagnostic.spans.iter().all(|span| span.start_line >= 1));
+    }
+
+    #[test]
+    fn type_check_error_diagnostics_have_spans() {
+        let result = compile_snippet(
+            "(define-read-only (get-one) (+ 1 u1))",
+            CompileOptions::default(),
+        );
+
+        let Err(CompileError::Generic {
+            diagnostics, code, ..
+        }) = result
+        else {
+            panic!("adding an int and a uint should not type-check");
+        };
+        assert_eq!(code, "TypeError");
+        let diagnostic = diagnostics
+            .iter()
+            .find(|d| matches!(d.level, clarity::vm::diagnostic::Level::Error))
+            .expect("expected an error diagnostic");
+        // A diagnostic rebuilt with `Diagnostic::err` would have no spans.
+        assert!(!diagnostic.spans.is_empty());
+    }
+
+    #[test]
+    fn exported_functions_have_their_signatures() {
+        use clarity::vm::types::{FixedFunction, FunctionType, TypeSignature};
+
+        let result = compile_snippet(
+            "
+            (define-public (add (a int) (b int)) (ok (+ a b)))
+            (define-read-only (get-one) u1)
+            (define-private (hidden) true)
+            ",
+            CompileOptions::default(),
+        )
+        .unwrap();
+
+        let exported = result.exported_functions();
+        assert_eq!(exported.len(), 2);
+
+        let add = &exported[0];
+        assert_eq!(add.name.as_str(), "add");
+        assert!(!add.read_only);
+        let FunctionType::Fixed(FixedFunction { args, returns }) = add.function_type else {
+            panic!("add should have a fixed signature");
+        };
+        let args: Vec<_> = args
+            .iter()
+            .map(|arg| (arg.name.as_str(), &arg.signature))
+            .collect();
+        assert_eq!(
+            args,
+            [
+                ("a", &TypeSignature::IntType),
+                ("b", &TypeSignature::IntType)
+            ]
+        );
+        assert!(
+            matches!(returns, TypeSignature::ResponseType(types) if types.0 == TypeSignature::IntType)
+        );
+
+        let get_one = &exported[1];
+        assert_eq!(get_one.name.as_str(), "get-one");
+        assert!(get_one.read_only);
+        assert!(matches!(
+            get_one.function_type,
+            FunctionType::Fixed(FixedFunction { args, returns })
+                if args.is_empty() && returns == &TypeSignature::UIntType
+        ));
+    }
+
+    #[test]
+    fn host_calls_are_counted() {
+        let result = compile_snippet(
+            "
+            (define-map balances principal uint)
+            (define-read-only (total (a principal) (b principal))
+                (+ (default-to u0 (map-get? balances a))
+                   (default-to u0 (map-get? balances b))))
+            ",
+            CompileOptions::default(),
+        )
+        .unwrap();
+
+        let host_calls = result.host_calls();
+        assert_eq!(host_calls.get("map_get"), Some(&2));
+        assert_eq!(host_calls.get("map_set"), None);
+    }
+
+    #[test]
+    fn read_only_calls_private_without_savepoint() {
+        let snippet = "
+            (define-data-var counter uint u41)
+            (define-private (next) (+ (var-get counter) u1))
+            (define-read-only (peek-next) (next))
+            (peek-next)
+        ";
+        crosscheck(snippet, Ok(Some(Value::UInt(42))));
+
+        let result = compile_snippet(snippet, CompileOptions::default()).unwrap();
+
+        // Only the top-level call to `peek-next` opens a read-only savepoint,
+        // the call to `next` is a plain call.
+        let host_calls = result.host_calls();
+        assert_eq!(host_calls.get("begin_read_only_call"), Some(&1));
+        assert_eq!(host_calls.get("roll_back_call"), Some(&1));
+        assert_eq!(host_calls.get("begin_public_call"), None);
+    }
+
+    #[test]
+    fn inner_public_err_keeps_outer_writes() {
+        crosscheck(
+            "
+            (define-data-var outer-before uint u0)
+            (define-data-var inner uint u0)
+            (define-data-var outer-after uint u0)
+            (define-public (fail)
+                (begin
+                    (var-set inner u2)
+                    (if true (err u3) (ok u4))))
+            (define-public (outer)
+                (begin
+                    (var-set outer-before u1)
+                    (var-set outer-after (match (fail) ok-value ok-value err-value err-value))
+                    (ok true)))
+            (outer)
+            {
+                outer-before: (var-get outer-before),
+                inner: (var-get inner),
+                outer-after: (var-get outer-after)
+            }
+            ",
+            evaluate("{ outer-before: u1, inner: u0, outer-after: u3 }"),
+        );
+    }
+
+    #[test]
+    fn literal_list_argument() {
+        crosscheck(
+            "(define-private (total (l (list 3 int))) (fold + l 0)) (total (list 1 2 3))",
+            Ok(Some(Value::Int(6))),
+        );
+    }
+
+    #[test]
+    fn composite_literal_is_a_clean_error() {
+        let mut generator = test_generator(CompileOptions::default());
+
+        let tuple = Value::from(TupleData::from_data(vec![("a".into(), Value::Int(1))]).unwrap());
+        match generator.add_literal(&tuple) {
+            Err(GeneratorError::TypeError(msg)) => {
+                assert!(msg.contains("Not a valid literal type"), "{msg}")
+            }
+            other => panic!("expected a type error, got {other:?}"),
+        }
+    }
+
+    #[test]
+    fn traits_are_not_values() {
+        use clarity::vm::representations::TraitDefinition;
+        use clarity::vm::types::TraitIdentifier;
+        use walrus::FunctionBuilder;
+
+        let mut generator = test_generator(CompileOptions::default());
+        let trait_identifier = TraitIdentifier {
+            name: "my-trait".into(),
+            contract_identifier: QualifiedContractIdentifier::new(
+                StandardPrincipalData::transient(),
+                ("my-trait-contract").into(),
+            ),
+        };
+
+        for expr in [
+            SymbolicExpression::field(trait_identifier.clone()),
+            SymbolicExpression::trait_reference(
+                "my-trait".into(),
+                TraitDefinition::Defined(trait_identifier),
+            ),
+        ] {
+            let mut function = FunctionBuilder::new(&mut generator.module.types, &[], &[]);
+            match generator.traverse_expr(&mut function.func_body(), &expr) {
+                Err(GeneratorError::TypeError(msg)) => {
+                    assert!(msg.contains("cannot be used as a value"), "{msg}")
+                }
+                other => panic!("expected a type error, got {other:?}"),
+            }
+        }
+    }
+
+    #[test]
+    fn bool_literal_value() {
+        use walrus::ir::{Const, Instr, Value as WasmValue};
+        use walrus::{FunctionBuilder, ValType};
+
+        // `true` and `false` in a contract are atoms, so push the literal
+        // values directly.
+        let mut generator = test_generator(CompileOptions::default());
+
+        for (value, expected) in [(true, 1), (false, 0)] {
+            let mut function =
+                FunctionBuilder::new(&mut generator.module.types, &[], &[ValType::I32]);
+            let mut body = function.func_body();
+            let value = Value::Bool(value);
+            generator
+                .visit_literal_value(
+                    &mut body,
+                    &SymbolicExpression::literal_value(value.clone()),
+                    &value,
+                )
+                .unwrap();
+            assert!(matches!(
+                body.instrs(),
+                [(Instr::Const(Const { value: WasmValue::I32(v) }), _)] if *v == expected
+            ));
+        }
+    }
+
+    #[test]
+    fn to_consensus_buff_checks_the_serialized_size() {
+        use clarity::vm::types::signatures::BufferLength;
+        use clarity::vm::types::{SequenceSubtype, TypeSignature, MAX_VALUE_SIZE};
+        use walrus::ir::{BinaryOp, Binop, Const, IfElse, Instr, Value as WasmValue};
+        use walrus::{FunctionBuilder, ValType};
+
+        // Analysis rejects any value whose serialization could be larger than
+        // MAX_VALUE_SIZE, so type a buffer of that length by hand: its
+        // serialization, with the type prefix, is larger.
+        let mut generator = test_generator(CompileOptions::default());
+        let buff = TypeSignature::SequenceType(SequenceSubtype::BufferType(
+            BufferLength::try_from(MAX_VALUE_SIZE).unwrap(),
+        ));
+        let mut arg = SymbolicExpression::literal_value(Value::buff_from(vec![0; 4]).unwrap());
+        arg.id = 1000;
+        let mut call = SymbolicExpression::list(vec![
+            SymbolicExpression::atom("to-consensus-buff?".into()),
+            arg.clone(),
+        ]);
+        call.id = 1001;
+        generator.set_expr_type(&arg, buff.clone()).unwrap();
+        generator
+            .set_expr_type(&call, TypeSignature::OptionalType(Box::new(buff)))
+            .unwrap();
+
+        let mut function = FunctionBuilder::new(
+            &mut generator.module.types,
+            &[],
+            &[ValType::I32, ValType::I32, ValType::I32],
+        );
+        let mut body = function.func_body();
+        generator.traverse_expr(&mut body, &call).unwrap();
+        let instrs: Vec<Instr> = body.instrs().iter().map(|(i, _)| i.clone()).collect();
+
+        // `(if (i32.le_u length MAX_VALUE_SIZE) (then some) (else none))`
+        let none_branch = instrs
+            .windows(3)
+            .find_map(|window| match window {
+                [Instr::Const(Const {
+                    value: WasmValue::I32(max),
+                }), Instr::Binop(Binop {
+                    op: BinaryOp::I32LeU,
+                }), Instr::IfElse(IfElse { alternative, .. })]
+                    if *max == MAX_VALUE_SIZE as i32 =>
+                {
+                    Some(*alternative)
+                }
+                _ => None,
+            })
+            .expect("the serialized size should be checked");
+        assert!(matches!(
+            function.instr_seq(none_branch).instrs(),
+            [
+                (
+                    Instr::Const(Const {
+                        value: WasmValue::I32(0)
+                    }),
+                    _
+                ),
+                (
+                    Instr::Const(Const {
+                        value: WasmValue::I32(0)
+                    }),
+                    _
+                ),
+                (
+                    Instr::Const(Const {
+                        value: WasmValue::I32(0)
+                    }),
+                    _
+                ),
+            ]
+        ));
+    }
+
+    #[test]
+    fn optional_and_response_literal_values() {
+        use clarity::vm::types::TypeSignature;
+        use walrus::ir::{Const, Instr, Value as WasmValue};
+        use walrus::FunctionBuilder;
+
+        use crate::wasm_generator::clar2wasm_ty;
+
+        // The parser never produces these literal values, contracts build
+        // them with `some`, `ok` and `err`, so push them directly.
+        let mut generator = test_generator(CompileOptions::default());
+
+        let optional_uint = TypeSignature::new_option(TypeSignature::UIntType).unwrap();
+        let response =
+            TypeSignature::new_response(TypeSignature::UIntType, TypeSignature::BoolType).unwrap();
+        let cases = [
+            (
+                Value::some(Value::UInt(7)).unwrap(),
+                optional_uint.clone(),
+                vec![WasmValue::I32(1), WasmValue::I64(7), WasmValue::I64(0)],
+            ),
+            (
+                Value::none(),
+                optional_uint,
+                vec![WasmValue::I32(0), WasmValue::I64(0), WasmValue::I64(0)],
+            ),
+            (
+                Value::okay(Value::UInt(7)).unwrap(),
+                response.clone(),
+                vec![
+                    WasmValue::I32(1),
+                    WasmValue::I64(7),
+                    WasmValue::I64(0),
+                    WasmValue::I32(0),
+                ],
+            ),
+            (
+                Value::error(Value::Bool(true)).unwrap(),
+                response,
+                vec![
+                    WasmValue::I32(0),
+                    WasmValue::I64(0),
+                    WasmValue::I64(0),
+                    WasmValue::I32(1),
+                ],
+            ),
+        ];
+
+        for (id, (value, ty, expected)) in cases.into_iter().enumerate() {
+            // Use ids that are not in the contract's type map.
+            let mut expr = SymbolicExpression::literal_value(value.clone());
+            expr.id = 1_000_000 + id as u64;
+            generator.set_expr_type(&expr, ty.clone()).unwrap();
+
+            let mut function =
+                FunctionBuilder::new(&mut generator.module.types, &[], &clar2wasm_ty(&ty));
+            let mut body = function.func_body();
+            generator
+                .visit_literal_value(&mut body, &expr, &value)
+                .unwrap();
+            let pushed: Vec<_> = body
+                .instrs()
+                .iter()
+                .map(|(instr, _)| match instr {
+                    Instr::Const(Const { value }) => *value,
+                    other => panic!("unexpected instruction {other:?}"),
+                })
+                .collect();
+            assert_eq!(
+                format!("{pushed:?}"),
+                format!("{expected:?}"),
+                "wrong layout for {value}"
+            );
+        }
+    }
+
+    #[test]
+    fn stack_checks_are_emitted() {
+        use walrus::ir::{BinaryOp, Binop, GlobalGet, IfElse, Instr};
+        use walrus::FunctionKind;
+
+        let snippet = "(define-private (add (a int) (b int)) (+ a b)) (add 1 2) (add 3 4)";
+        // Whether `add` ends with `(if (i32.lt_u (global.get $stack-pointer)
+        // (local.get $frame)) (then unreachable))`.
+        let has_stack_check = |options: CompileOptions| {
+            let module = compile_snippet(snippet, options).unwrap().module;
+            let stack_pointer = get_global(&module, "stack-pointer").unwrap();
+            let function = module
+                .funcs
+                .iter()
+                .find(|f| f.name.as_deref() == Some("add"))
+                .expect("add should be compiled");
+            let FunctionKind::Local(local) = &function.kind else {
+                panic!("add should be a local function");
+            };
+            let instrs: Vec<&Instr> = local
+                .block(local.entry_block())
+                .instrs
+                .iter()
+                .map(|(instr, _)| instr)
+                .collect();
+            instrs.windows(4).any(|window| match window {
+                [Instr::GlobalGet(GlobalGet { global }), Instr::LocalGet(_), Instr::Binop(Binop {
+                    op: BinaryOp::I32LtU,
+                }), Instr::IfElse(IfElse { consequent, .. })] => {
+                    *global == stack_pointer
+                        && matches!(
+                            local.block(*consequent).instrs.as_slice(),
+                            [(Instr::Unreachable(_), _)]
+                        )
+                }
+                _ => false,
+            })
+        };
+
+        assert!(!has_stack_check(CompileOptions::default()));
+        assert!(has_stack_check(CompileOptions {
+            stack_checks: true,
+            ..Default::default()
+        }));
+    }
+
+    #[test]
+    fn strip_unused_stdlib_removes_functions() {
+        let function_count = |strip_unused_stdlib| {
+            compile_snippet(
+                "(define-read-only (f) 1)",
+                CompileOptions {
+                    strip_unused_stdlib,
+                    ..Default::default()
+                },
+            )
+            .unwrap()
+            .module
+            .funcs
+            .iter()
+            .count()
+        };
+
+        let full = function_count(false);
+        let stripped = function_count(true);
+        assert!(
+            stripped * 10 < full,
+            "{stripped} functions left out of {full}"
+        );
+    }
+
+    #[test]
+    fn strip_unused_stdlib_preserves_results() {
+        let snippet = r#"
+            (define-map balances principal uint)
+            (define-public (deposit (amount uint))
+                (begin
+                    (map-set balances tx-sender (+ amount (default-to u0 (map-get? balances tx-sender))))
+                    (ok (sha256 (unwrap-panic (to-consensus-buff? amount))))))
+            (list (deposit u10) (deposit u20))
+        "#;
+
+        let mut env = TestEnvironment::default();
+        env.compile_options.strip_unused_stdlib = true;
+        assert_eq!(env.evaluate(snippet).unwrap(), evaluate(snippet).unwrap());
+    }
+
+    /// A word standing in for `not` that always gives `false`.
+    #[derive(Debug)]
+    struct FalseNot;
+
+    impl crate::ComplexWord for FalseNot {
+        fn name(&self) -> ClarityName {
+            "not".into()
+        }
+
+        fn traverse(
+            &self,
+            _generator: &mut WasmGenerator,
+            builder: &mut walrus::InstrSeqBuilder,
+            _expr: &SymbolicExpression,
+            _args: &[SymbolicExpression],
+        ) -> Result<(), GeneratorError> {
+            builder.i32_const(0);
+            Ok(())
+        }
+    }
+
+    /// A word pushing `true`, whatever its arguments.
+    #[derive(Debug)]
+    struct StubWord;
+
+    impl crate::ComplexWord for StubWord {
+        fn name(&self) -> ClarityName {
+            "stubbed-word".into()
+        }
+
+        fn traverse(
+            &self,
+            _generator: &mut WasmGenerator,
+            builder: &mut walrus::InstrSeqBuilder,
+            _expr: &SymbolicExpression,
+            _args: &[SymbolicExpression],
+        ) -> Result<(), GeneratorError> {
+            builder.i32_const(1);
+            Ok(())
+        }
+    }
+
+    static STUB_WORDS: &[&dyn crate::ComplexWord] = &[&FalseNot, &StubWord];
+
+    #[test]
+    fn extra_words_stand_in_for_natives() {
+        let snippet = "(define-read-only (check) (not false)) (check)";
+
+        let mut env = TestEnvironment::default();
+        env.compile_options.extra_words = STUB_WORDS;
+        assert_eq!(env.evaluate(snippet), Ok(Some(Value::Bool(false))));
+
+        let mut env = TestEnvironment::default();
+        assert_eq!(env.evaluate(snippet), Ok(Some(Value::Bool(true))));
+    }
+
+    #[test]
+    fn extra_words_do_not_shadow_contract_functions() {
+        let snippet = "
+            (define-private (stubbed-word (n int)) (+ n 1))
+            (stubbed-word 41)
+        ";
+
+        let mut env = TestEnvironment::default();
+        env.compile_options.extra_words = STUB_WORDS;
+        assert_eq!(env.evaluate(snippet), Ok(Some(Value::Int(42))));
+    }
+
+    #[test]
+    fn stack_checks_preserve_results() {
+        let snippet = r#"
+            (define-data-var names (list 5 (string-ascii 10)) (list "alice"))
+            (define-private (greet (name (string-ascii 10)))
+                (concat "hi " name))
+            (define-private (sum (a int) (acc int))
+                (+ a acc))
+            (define-public (register (name (string-ascii 10)))
+                (begin
+                    (var-set names (unwrap-panic (as-max-len? (append (var-get names) name) u5)))
+                    (ok (map greet (var-get names)))))
+            {
+                greetings: (unwrap-panic (register "bob")),
+                total: (fold sum (list 1 2 3 4) 0),
+            }
+        "#;
+
+        let mut env = TestEnvironment::default();
+        env.compile_options.stack_checks = true;
+        assert_eq!(
+            env.evaluate(snippet).unwrap(),
+            evaluate(r#"{greetings: (list "hi alice" "hi bob"), total: 10}"#).unwrap()
+        );
     }
 
     #[test]
@@ -2415,6 +3618,35 @@ mod tests {
         crosscheck(snippet, Ok(Some(expected)));
     }
 
+    #[test]
+    fn defensive_not_implemented_function_names_the_function() {
+        use clarity::vm::diagnostic::Diagnostic;
+        use walrus::{FunctionBuilder, ValType};
+
+        // Every native function has a word, so make the call that
+        // `traverse_list` makes for a name without one.
+        let mut generator = test_generator(CompileOptions::default());
+        let name = ClarityName::from("secp256k1-verify");
+        let args = [
+            SymbolicExpression::literal_value(Value::buff_from(vec![0; 32]).unwrap()),
+            SymbolicExpression::literal_value(Value::buff_from(vec![0; 65]).unwrap()),
+            SymbolicExpression::literal_value(Value::buff_from(vec![0; 33]).unwrap()),
+        ];
+        let mut call = vec![SymbolicExpression::atom(name.clone())];
+        call.extend(args.iter().cloned());
+        let call = SymbolicExpression::list(call);
+
+        let mut function = FunctionBuilder::new(&mut generator.module.types, &[], &[ValType::I32]);
+        let err = generator
+            .traverse_call_without_word(&mut function.func_body(), &call, &name, &args)
+            .unwrap_err();
+        assert!(matches!(&err, GeneratorError::NotImplementedFunction(n) if *n == name));
+        assert_eq!(
+            Diagnostic::err(&err).message,
+            "Not implemented: secp256k1-verify"
+        );
+    }
+
     //
     // Module with tests that should only be executed
     // when running Clarity::V2 or Clarity::v3.
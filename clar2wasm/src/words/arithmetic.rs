@@ -262,7 +262,10 @@ mod tests {
     use clarity::vm::errors::{Error, RuntimeErrorType};
     use clarity::vm::Value;
 
-    use crate::tools::{crosscheck, crosscheck_expect_failure, evaluate};
+    use crate::tools::{
+        crosscheck, crosscheck_compare_only_with_expected_error, crosscheck_expect_failure,
+        evaluate,
+    };
 
     #[test]
     fn test_overflow() {
@@ -343,6 +346,43 @@ mod tests {
         crosscheck("(mod 8 3)", Ok(Some(Value::Int(2))));
     }
 
+    #[test]
+    fn test_div_by_zero() {
+        for snippet in ["(/ 10 0)", "(/ u10 u0)"] {
+            crosscheck(
+                snippet,
+                Err(Error::Runtime(
+                    RuntimeErrorType::DivisionByZero,
+                    Some(Vec::new()),
+                )),
+            );
+        }
+    }
+
+    #[test]
+    fn test_mod_by_zero() {
+        for snippet in ["(mod 10 0)", "(mod u10 u0)"] {
+            crosscheck(
+                snippet,
+                Err(Error::Runtime(
+                    RuntimeErrorType::DivisionByZero,
+                    Some(Vec::new()),
+                )),
+            );
+        }
+    }
+
+    #[test]
+    fn test_div_min_by_minus_one() {
+        crosscheck(
+            "(/ -170141183460469231731687303715884105728 -1)",
+            Err(Error::Runtime(
+                RuntimeErrorType::ArithmeticOverflow,
+                Some(Vec::new()),
+            )),
+        );
+    }
+
     #[test]
     fn test_log2() {
         crosscheck("(log2 8)", Ok(Some(Value::Int(3))));
@@ -364,6 +404,39 @@ mod tests {
         crosscheck("(pow 2 3)", Ok(Some(Value::Int(8))));
     }
 
+    #[test]
+    fn test_pow_large_uint() {
+        crosscheck(
+            "(pow u3 u80)",
+            Ok(Some(Value::UInt(147808829414345923316083210206383297601))),
+        );
+    }
+
+    #[test]
+    fn test_pow_large_int() {
+        crosscheck("(pow 2 126)", Ok(Some(Value::Int(1 << 126))));
+    }
+
+    #[test]
+    fn test_pow_uint_overflow() {
+        for snippet in ["(pow u2 u128)", "(pow u3 u81)"] {
+            assert!(evaluate(snippet).is_err());
+            crosscheck_compare_only_with_expected_error(snippet, |e| {
+                matches!(e, Error::Runtime(..))
+            });
+        }
+    }
+
+    #[test]
+    fn test_pow_int_overflow() {
+        for snippet in ["(pow 2 127)", "(pow -3 81)"] {
+            assert!(evaluate(snippet).is_err());
+            crosscheck_compare_only_with_expected_error(snippet, |e| {
+                matches!(e, Error::Runtime(..))
+            });
+        }
+    }
+
     #[test]
     fn test_pow_negative_exponent() {
         crosscheck(
@@ -382,6 +455,42 @@ mod tests {
         crosscheck("(sqrti 8)", Ok(Some(Value::Int(2))));
     }
 
+    #[test]
+    fn test_sqrti_uint_perfect_square() {
+        crosscheck("(sqrti u144)", Ok(Some(Value::UInt(12))));
+    }
+
+    #[test]
+    fn test_sqrti_zero() {
+        crosscheck("(sqrti 0)", Ok(Some(Value::Int(0))));
+    }
+
+    #[test]
+    fn test_sqrti_uint_max() {
+        crosscheck(
+            "(sqrti u340282366920938463463374607431768211455)",
+            Ok(Some(Value::UInt(u64::MAX as u128))),
+        );
+    }
+
+    #[test]
+    fn test_log2_uint() {
+        crosscheck("(log2 u1024)", Ok(Some(Value::UInt(10))));
+    }
+
+    #[test]
+    fn test_log2_rounds_down() {
+        crosscheck("(log2 u1023)", Ok(Some(Value::UInt(9))));
+    }
+
+    #[test]
+    fn test_log2_uint_max() {
+        crosscheck(
+            "(log2 u340282366920938463463374607431768211455)",
+            Ok(Some(Value::UInt(127))),
+        );
+    }
+
     #[test]
     fn test_sqrti_runtime_error() {
         crosscheck(
@@ -158,6 +158,47 @@ mod tests {
         )
     }
 
+    #[test]
+    fn let_binding_uses_previous_binding() {
+        crosscheck(
+            "(let ((a 2) (b (* a 3)) (c (+ a b))) (list a b c))",
+            evaluate("(list 2 6 8)"),
+        );
+    }
+
+    #[test]
+    fn let_bindings_do_not_leak_into_siblings() {
+        crosscheck(
+            "(+ (let ((x 1)) x) (let ((x 2) (y x)) y))",
+            Ok(Some(Value::Int(3))),
+        );
+    }
+
+    #[test]
+    fn let_keeps_function_parameters() {
+        crosscheck(
+            "
+(define-private (f (a int))
+  (+ (let ((b (* a 2))) b) a))
+(f 5)
+",
+            Ok(Some(Value::Int(15))),
+        );
+    }
+
+    #[test]
+    fn let_cannot_shadow_function_parameter() {
+        // Clarity does not allow shadowing, so this fails in both
+        // implementations rather than returning the inner value.
+        crosscheck_expect_failure(
+            "
+(define-private (f (a int))
+  (let ((a 2)) a))
+(f 5)
+",
+        );
+    }
+
     #[test]
     fn validate_let() {
         // Reserved keyword
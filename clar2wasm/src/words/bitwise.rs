@@ -178,6 +178,8 @@ impl SimpleWord for Xor {
 mod tests {
     #[cfg(test)]
     mod clarity_v2_v3 {
+        use clarity::vm::Value;
+
         use crate::tools::{crosscheck, evaluate};
 
         #[test]
@@ -240,6 +242,42 @@ mod tests {
             )
         }
 
+        #[test]
+        fn test_bit_shift_left_across_64_bits() {
+            crosscheck("(bit-shift-left u1 u70)", Ok(Some(Value::UInt(1 << 70))));
+            crosscheck("(bit-shift-left 3 u70)", Ok(Some(Value::Int(3 << 70))));
+            crosscheck("(bit-shift-left -1 u70)", Ok(Some(Value::Int(-1 << 70))));
+        }
+
+        #[test]
+        fn test_bit_shift_left_amount_is_modulo_128() {
+            crosscheck("(bit-shift-left u5 u200)", Ok(Some(Value::UInt(5 << 72))));
+        }
+
+        #[test]
+        fn test_bit_shift_right_across_64_bits() {
+            crosscheck(
+                "(bit-shift-right u1267650600228229401496703205376 u70)",
+                Ok(Some(Value::UInt(1 << 30))),
+            );
+        }
+
+        #[test]
+        fn test_bit_shift_right_int_is_arithmetic() {
+            crosscheck(
+                "(bit-shift-right -1267650600228229401496703205376 u70)",
+                Ok(Some(Value::Int(-(1 << 30)))),
+            );
+        }
+
+        #[test]
+        fn test_bit_shift_right_uint_is_logical() {
+            crosscheck(
+                "(bit-shift-right u340282366920938463463374607431768211455 u127)",
+                Ok(Some(Value::UInt(1))),
+            );
+        }
+
         #[test]
         fn test_bitwise_xor() {
             crosscheck(
@@ -46,6 +46,9 @@ impl ComplexWord for GetBlockInfo {
             "time" => {
                 builder.call(generator.func_by_name("stdlib.get_block_info_time_property"));
             }
+            "vrf-seed" => {
+                builder.call(generator.func_by_name("stdlib.get_block_info_vrf_seed_property"));
+            }
             "header-hash" => {
                 builder.call(generator.func_by_name("stdlib.get_block_info_header_hash_property"));
             }
@@ -346,6 +349,7 @@ mod tests {
     use clarity::vm::types::{OptionalData, PrincipalData, TupleData};
     use clarity::vm::Value;
 
+    use crate::datastore::{BlockInfo, BurnDatastore, HostInterface, StacksConstants};
     use crate::tools::{evaluate, TestEnvironment};
 
     //
@@ -624,6 +628,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn get_block_info_burnchain_header_hash_length() {
+        let mut env = TestEnvironment::default();
+        env.advance_chain_tip(1);
+        let result = env
+            .evaluate("(len (unwrap-panic (get-block-info? burnchain-header-hash u0)))")
+            .expect("Failed to init contract.");
+        assert_eq!(result, Some(Value::UInt(32)));
+    }
+
+    #[test]
+    fn get_block_info_vrf_seed() {
+        let mut env = TestEnvironment::default();
+        env.advance_chain_tip(1);
+        let result = env
+            .evaluate("(get-block-info? vrf-seed u0)")
+            .expect("Failed to init contract.");
+        assert_eq!(
+            result,
+            Some(Value::some(Value::buff_from([0; 32].to_vec()).unwrap()).unwrap())
+        );
+    }
+
+    #[test]
+    fn get_block_info_vrf_seed_non_existent() {
+        let mut env = TestEnvironment::default();
+        env.advance_chain_tip(1);
+        let result = env
+            .evaluate("(get-block-info? vrf-seed u9999999)")
+            .expect("Failed to init contract.");
+        assert_eq!(result, Some(Value::none()));
+    }
+
     #[test]
     fn get_block_info_id_header_hash() {
         let mut env = TestEnvironment::default();
@@ -674,6 +711,64 @@ mod tests {
         )
     }
 
+    #[test]
+    fn get_block_info_custom_time() {
+        let mut env = TestEnvironment::default();
+        env.advance_chain_tip(2);
+        assert!(env.set_block_time(1, 1_700_000_000));
+        let result = env
+            .evaluate("(get-block-info? time u1)")
+            .expect("Failed to init contract.");
+        assert_eq!(
+            result,
+            Some(Value::some(Value::UInt(1_700_000_000)).unwrap())
+        );
+    }
+
+    /// A chain whose blocks all have the same time.
+    struct FixedTimeHost(BurnDatastore);
+
+    impl HostInterface for FixedTimeHost {
+        fn tip_height(&self) -> u32 {
+            self.0.tip_height()
+        }
+
+        fn block_height(&self, id: &clarity::types::chainstate::StacksBlockId) -> Option<u32> {
+            self.0.block_height(id)
+        }
+
+        fn block_info(&self, height: u32) -> Option<BlockInfo> {
+            self.0.block_info(height).map(|block| BlockInfo {
+                burn_block_time: 1_700_000_000,
+                ..block
+            })
+        }
+
+        fn constants(&self) -> &StacksConstants {
+            self.0.constants()
+        }
+    }
+
+    #[test]
+    fn get_block_info_time_from_custom_host() {
+        let mut chain = BurnDatastore::new(StacksConstants::default());
+        chain.advance_chain_tip(2);
+
+        let mut env = TestEnvironment::default();
+        env.advance_chain_tip(2);
+        let result = env
+            .init_contract_with_host(
+                "snippet",
+                "(get-block-info? time u1)",
+                &FixedTimeHost(chain),
+            )
+            .expect("Failed to init contract.");
+        assert_eq!(
+            result,
+            Some(Value::some(Value::UInt(1_700_000_000)).unwrap())
+        );
+    }
+
     #[test]
     fn get_block_info_time() {
         let mut env = TestEnvironment::default();
@@ -821,6 +916,47 @@ mod tests {
         );
     }
 
+    #[test]
+    fn at_block_nested_restores_outer_block() {
+        let mut env = TestEnvironment::default();
+        env.init_contract_with_snippet(
+            "counter",
+            "
+(define-data-var n int 0)
+(define-public (set (v int)) (ok (var-set n v)))
+(define-read-only (get-n) (ok (var-get n)))
+",
+        )
+        .expect("Failed to init contract.");
+
+        // `n` is 1 at block 1 and 2 at block 2.
+        for (height, contract) in [(1, "set-1"), (2, "set-2")] {
+            env.advance_chain_tip(1);
+            env.init_contract_with_snippet(
+                contract,
+                &format!("(contract-call? .counter set {height})"),
+            )
+            .expect("Failed to init contract.");
+        }
+        env.advance_chain_tip(1);
+
+        let snippet = "
+(define-read-only (get-n) (unwrap-panic (contract-call? .counter get-n)))
+(define-read-only (id (height uint)) (unwrap-panic (get-block-info? id-header-hash height)))
+(let ((block-1 (id u1)) (block-2 (id u2)))
+  (at-block block-1
+    (list
+      (get-n)
+      (at-block block-2 (get-n))
+      (get-n))))
+";
+        let compiled = env.clone().init_contract_with_snippet("reader", snippet);
+        let interpreted = env.interpret_contract_with_snippet("reader", snippet);
+
+        assert_eq!(compiled, evaluate("(list 1 2 1)"));
+        assert_eq!(compiled, interpreted);
+    }
+
     //
     // Module with tests that should only be executed
     // when running Clarity::V2 or Clarity::v3.
@@ -96,3 +96,135 @@ impl SimpleWord for BuffToIntLe {
         traverse_buffer_to_integer("stdlib.buff-to-uint-le", generator, builder)
     }
 }
+
+#[cfg(not(feature = "test-clarity-v1"))]
+#[cfg(test)]
+mod tests {
+    #[cfg(test)]
+    mod clarity_v2_v3 {
+        use clarity::vm::Value;
+
+        use crate::tools::crosscheck;
+
+        #[test]
+        fn buff_to_uint_le_single_byte() {
+            crosscheck("(buff-to-uint-le 0x01)", Ok(Some(Value::UInt(1))));
+        }
+
+        #[test]
+        fn buff_to_int_le_single_byte() {
+            crosscheck("(buff-to-int-le 0x01)", Ok(Some(Value::Int(1))));
+        }
+
+        #[test]
+        fn buff_to_uint_be_single_byte() {
+            crosscheck("(buff-to-uint-be 0x01)", Ok(Some(Value::UInt(1))));
+        }
+
+        #[test]
+        fn buff_to_int_be_single_byte() {
+            crosscheck("(buff-to-int-be 0x01)", Ok(Some(Value::Int(1))));
+        }
+
+        #[test]
+        fn buff_to_uint_le_full() {
+            crosscheck(
+                "(buff-to-uint-le 0x0102030405060708090a0b0c0d0e0f10)",
+                Ok(Some(Value::UInt(u128::from_le_bytes([
+                    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+                ])))),
+            );
+        }
+
+        #[test]
+        fn buff_to_int_le_full() {
+            crosscheck(
+                "(buff-to-int-le 0xffffffffffffffffffffffffffffffff)",
+                Ok(Some(Value::Int(-1))),
+            );
+        }
+
+        #[test]
+        fn buff_to_uint_be_full() {
+            crosscheck(
+                "(buff-to-uint-be 0x0102030405060708090a0b0c0d0e0f10)",
+                Ok(Some(Value::UInt(u128::from_be_bytes([
+                    1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16,
+                ])))),
+            );
+        }
+
+        #[test]
+        fn buff_to_int_be_full() {
+            crosscheck(
+                "(buff-to-int-be 0xfffffffffffffffffffffffffffffffe)",
+                Ok(Some(Value::Int(-2))),
+            );
+        }
+
+        #[test]
+        fn buff_to_int_le_short_is_not_sign_extended() {
+            // Buffers shorter than 16 bytes are zero-padded, so the sign bit
+            // of the last byte does not propagate.
+            crosscheck("(buff-to-int-le 0xff)", Ok(Some(Value::Int(255))));
+        }
+
+        #[test]
+        fn buff_to_int_be_short_is_not_sign_extended() {
+            crosscheck("(buff-to-int-be 0xff00)", Ok(Some(Value::Int(0xff00))));
+        }
+
+        #[test]
+        fn buff_to_uint_le_empty() {
+            crosscheck("(buff-to-uint-le 0x)", Ok(Some(Value::UInt(0))));
+        }
+
+        #[test]
+        fn buff_to_int_le_empty() {
+            crosscheck("(buff-to-int-le 0x)", Ok(Some(Value::Int(0))));
+        }
+
+        #[test]
+        fn buff_to_uint_be_empty() {
+            crosscheck("(buff-to-uint-be 0x)", Ok(Some(Value::UInt(0))));
+        }
+
+        #[test]
+        fn buff_to_int_be_empty() {
+            crosscheck("(buff-to-int-be 0x)", Ok(Some(Value::Int(0))));
+        }
+
+        #[test]
+        fn buff_to_uint_be_all_ones() {
+            crosscheck(
+                "(buff-to-uint-be 0xffffffffffffffffffffffffffffffff)",
+                Ok(Some(Value::UInt(u128::MAX))),
+            );
+        }
+
+        #[test]
+        fn buff_to_uint_be_top_bit_set() {
+            crosscheck(
+                "(buff-to-uint-be 0x80000000000000000000000000000001)",
+                Ok(Some(Value::UInt((1 << 127) + 1))),
+            );
+        }
+
+        #[test]
+        fn buff_to_int_be_top_bit_set() {
+            // The same bytes read as a signed integer wrap to a negative value.
+            crosscheck(
+                "(buff-to-int-be 0x80000000000000000000000000000001)",
+                Ok(Some(Value::Int(i128::MIN + 1))),
+            );
+        }
+
+        #[test]
+        fn buff_to_uint_be_three_bytes() {
+            crosscheck(
+                "(buff-to-uint-be 0x010203)",
+                Ok(Some(Value::UInt(0x010203))),
+            );
+        }
+    }
+}
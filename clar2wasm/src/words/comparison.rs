@@ -120,3 +120,71 @@ impl SimpleWord for CmpGeq {
         traverse_comparison("ge", generator, builder, arg_types, return_type)
     }
 }
+
+#[cfg(not(feature = "test-clarity-v1"))]
+#[cfg(test)]
+mod tests {
+    #[cfg(test)]
+    mod clarity_v2_v3 {
+        use clarity::vm::Value;
+
+        use crate::tools::crosscheck;
+
+        #[test]
+        fn buffer_lt() {
+            crosscheck("(< 0x0102 0x0103)", Ok(Some(Value::Bool(true))));
+            crosscheck("(< 0x0103 0x0102)", Ok(Some(Value::Bool(false))));
+        }
+
+        #[test]
+        fn buffer_le() {
+            crosscheck("(<= 0x0102 0x0102)", Ok(Some(Value::Bool(true))));
+            crosscheck("(<= 0x0102 0x01)", Ok(Some(Value::Bool(false))));
+        }
+
+        #[test]
+        fn buffer_gt() {
+            // A longer buffer sharing a prefix is greater.
+            crosscheck("(> 0x010200 0x0102)", Ok(Some(Value::Bool(true))));
+            crosscheck("(> 0x 0x00)", Ok(Some(Value::Bool(false))));
+        }
+
+        #[test]
+        fn buffer_ge() {
+            crosscheck("(>= 0xff 0x0102)", Ok(Some(Value::Bool(true))));
+            crosscheck("(>= 0x0102 0xff)", Ok(Some(Value::Bool(false))));
+        }
+
+        #[test]
+        fn string_ascii_lt() {
+            crosscheck(r#"(< "abc" "abd")"#, Ok(Some(Value::Bool(true))));
+            crosscheck(r#"(< "b" "abc")"#, Ok(Some(Value::Bool(false))));
+        }
+
+        #[test]
+        fn string_ascii_le() {
+            crosscheck(r#"(<= "abc" "abc")"#, Ok(Some(Value::Bool(true))));
+            crosscheck(r#"(<= "abcd" "abc")"#, Ok(Some(Value::Bool(false))));
+        }
+
+        #[test]
+        fn string_ascii_gt() {
+            crosscheck(r#"(> "abc" "ab")"#, Ok(Some(Value::Bool(true))));
+            crosscheck(r#"(> "" "a")"#, Ok(Some(Value::Bool(false))));
+        }
+
+        #[test]
+        fn string_ascii_ge() {
+            crosscheck(r#"(>= "b" "abc")"#, Ok(Some(Value::Bool(true))));
+            crosscheck(r#"(>= "A" "a")"#, Ok(Some(Value::Bool(false))));
+        }
+
+        #[test]
+        fn string_utf8_lt() {
+            crosscheck(
+                r#"(< u"a\u{0141}" u"a\u{1F98A}")"#,
+                Ok(Some(Value::Bool(true))),
+            );
+        }
+    }
+}
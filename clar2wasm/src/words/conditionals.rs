@@ -926,6 +926,29 @@ mod tests {
             .contains("expecting 3 arguments, got 4"));
     }
 
+    #[test]
+    fn if_untaken_branch_has_no_side_effects() {
+        crosscheck(
+            "
+(define-data-var x int 0)
+(define-data-var y int 0)
+(list
+  (if true (var-set x 1) (var-set y 1))
+  (if false (var-set x 2) (var-set y 2)))
+(list (var-get x) (var-get y))
+",
+            evaluate("(list 1 2)"),
+        );
+    }
+
+    #[test]
+    fn if_in_memory_branches() {
+        crosscheck(
+            r#"(list (if (> 2 1) "yes" "no") (if (< 2 1) "yes" "no"))"#,
+            evaluate(r#"(list "yes" "no")"#),
+        );
+    }
+
     #[test]
     fn what_if() {
         crosscheck("(if true true false)", Ok(Some(Value::Bool(true))));
@@ -978,6 +1001,45 @@ mod tests {
         );
     }
 
+    #[test]
+    fn filter_is_even() {
+        crosscheck(
+            "
+(define-private (is-even (number int))
+  (is-eq (mod number 2) 0))
+
+(filter is-even (list 1 2 3 4))
+",
+            evaluate("(list 2 4)"),
+        );
+    }
+
+    #[test]
+    fn filter_everything_out() {
+        crosscheck(
+            "
+(define-private (is-even (number int))
+  (is-eq (mod number 2) 0))
+
+(filter is-even (list 1 3 5 7))
+",
+            evaluate("(list)"),
+        );
+    }
+
+    #[test]
+    fn filter_in_memory_elements() {
+        crosscheck(
+            r#"
+(define-private (is-short (s (string-ascii 8)))
+  (< (len s) u4))
+
+(filter is-short (list "a" "longer" "abc" "very long" ""))
+"#,
+            evaluate(r#"(list "a" "abc" "")"#),
+        );
+    }
+
     #[test]
     fn filter_builtin() {
         crosscheck(
@@ -1036,6 +1098,18 @@ mod tests {
         );
     }
 
+    #[test]
+    fn filter_string_utf8_multi_byte() {
+        crosscheck(
+            r#"
+(define-private (is-not-fox (char (string-utf8 1)))
+    (not (is-eq char u"\u{1F98A}"))
+)
+(filter is-not-fox u"\u{1F98A}a\u{1F98A}\u{0141}\u{1F98A}")"#,
+            evaluate(r#"u"a\u{0141}""#),
+        );
+    }
+
     #[test]
     fn nested_logical() {
         crosscheck(
@@ -1101,6 +1175,21 @@ mod tests {
         );
     }
 
+    #[test]
+    fn and_or_skip_side_effects_once_decided() {
+        crosscheck(
+            "
+(define-data-var touched bool false)
+(define-private (touch)
+  (begin
+    (var-set touched true)
+    true))
+(list (and false (touch)) (or true (touch)) (var-get touched))
+",
+            evaluate("(list false true false)"),
+        );
+    }
+
     #[test]
     fn match_less_than_two_args() {
         crosscheck_expect_failure(
@@ -1143,6 +1232,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn match_response_both_arms() {
+        const STEP: &str = "
+(define-private (step (resp (response int int)))
+ (match resp
+   v (+ v 1)
+   e (- e 1)))";
+
+        crosscheck(&format!("{STEP} (step (ok 1))"), Ok(Some(Value::Int(2))));
+        crosscheck(&format!("{STEP} (step (err 1))"), Ok(Some(Value::Int(0))));
+    }
+
+    #[test]
+    fn match_response_different_arm_types() {
+        const DESCRIBE: &str = r#"
+(define-private (describe (resp (response uint (string-ascii 8))))
+ (match resp
+   v (if (> v u10) "big" "small")
+   e e))"#;
+
+        crosscheck(
+            &format!("{DESCRIBE} (describe (ok u42))"),
+            evaluate(r#""big""#),
+        );
+        crosscheck(
+            &format!(r#"{DESCRIBE} (describe (err "oops"))"#),
+            evaluate(r#""oops""#),
+        );
+    }
+
     #[test]
     fn clar_match_disallow_builtin_names() {
         // It's not allowed to use names of user-defined functions as bindings
@@ -1207,6 +1326,39 @@ mod tests {
         );
     }
 
+    #[test]
+    fn match_optional_doubles_some() {
+        const DOUBLE: &str = "
+(define-private (double-or-zero (x (optional int)))
+ (match x
+   val (* val 2)
+   0))";
+
+        crosscheck(
+            &format!("{DOUBLE} (double-or-zero (some 21))"),
+            Ok(Some(Value::Int(42))),
+        );
+        crosscheck(
+            &format!("{DOUBLE} (double-or-zero none)"),
+            Ok(Some(Value::Int(0))),
+        );
+    }
+
+    #[test]
+    fn match_optional_in_memory_binding() {
+        const GREET: &str = r#"
+(define-private (greet (name (optional (string-ascii 8))))
+ (match name
+   n (concat "hi " n)
+   "nobody"))"#;
+
+        crosscheck(
+            &format!(r#"{GREET} (greet (some "bob"))"#),
+            evaluate(r#""hi bob""#),
+        );
+        crosscheck(&format!("{GREET} (greet none)"), evaluate(r#""nobody""#));
+    }
+
     #[test]
     fn unwrap_less_than_two_args() {
         let result = evaluate("(define-private (unwrapper (x (optional int))) (+ (unwrap! x) 10))");
@@ -1259,6 +1411,36 @@ mod tests {
         );
     }
 
+    #[test]
+    fn unwrap_early_return_custom_error() {
+        const FN: &str = "
+(define-public (withdraw (amount (optional uint)))
+  (let ((value (unwrap! amount (err u404))))
+    (ok (+ value u1))))";
+
+        crosscheck(&format!("{FN} (withdraw (some u41))"), evaluate("(ok u42)"));
+
+        crosscheck(&format!("{FN} (withdraw none)"), evaluate("(err u404)"));
+    }
+
+    #[test]
+    fn unwrap_err_early_return_in_memory_error() {
+        const FN: &str = r#"
+(define-public (failure-reason (res (response uint (string-ascii 16))))
+  (let ((reason (unwrap-err! res (err "no failure"))))
+    (ok reason)))"#;
+
+        crosscheck(
+            &format!(r#"{FN} (failure-reason (err "out of funds"))"#),
+            evaluate(r#"(ok "out of funds")"#),
+        );
+
+        crosscheck(
+            &format!("{FN} (failure-reason (ok u1))"),
+            evaluate(r#"(err "no failure")"#),
+        );
+    }
+
     #[test]
     fn unwrap_err_less_than_two_args() {
         let result =
@@ -1393,6 +1575,30 @@ mod tests {
         );
     }
 
+    const TRY_PUBLIC: &str = "
+(define-private (checked-sub (a uint) (b uint))
+  (if (>= a b) (ok (- a b)) (err u1)))
+
+(define-public (withdraw (balance uint) (amount uint))
+  (let ((remaining (try! (checked-sub balance amount))))
+    (ok remaining)))";
+
+    #[test]
+    fn try_public_passes_through() {
+        crosscheck(
+            &format!("{TRY_PUBLIC} (withdraw u10 u3)"),
+            evaluate("(ok u7)"),
+        );
+    }
+
+    #[test]
+    fn try_public_short_circuits_err() {
+        crosscheck(
+            &format!("{TRY_PUBLIC} (withdraw u3 u10)"),
+            evaluate("(err u1)"),
+        );
+    }
+
     #[test]
     fn try_less_than_one_arg() {
         let result =
@@ -1441,6 +1647,31 @@ mod tests {
         );
     }
 
+    const ASSERT_PUBLIC: &str = "
+(define-data-var counter uint u0)
+
+(define-public (increment-by (amount uint))
+  (begin
+    (asserts! (> amount u0) (err u100))
+    (var-set counter (+ (var-get counter) amount))
+    (ok (var-get counter))))";
+
+    #[test]
+    fn asserts_public_passes() {
+        crosscheck(
+            &format!("{ASSERT_PUBLIC} (increment-by u5)"),
+            evaluate("(ok u5)"),
+        );
+    }
+
+    #[test]
+    fn asserts_public_fails() {
+        crosscheck(
+            &format!("{ASSERT_PUBLIC} (increment-by u0)"),
+            evaluate("(err u100)"),
+        );
+    }
+
     #[test]
     fn asserts_top_level_true() {
         crosscheck("(asserts! true (err u1))", Ok(Some(Value::Bool(true))));
@@ -177,11 +177,14 @@ mod tests {
         use std::collections::BTreeSet;
         use std::fmt::Write as _;
 
-        use clarity::vm::types::{BuffData, PrincipalData, SequenceData, TupleData};
+        use clarity::vm::types::{
+            BuffData, PrincipalData, QualifiedContractIdentifier, SequenceData,
+            StandardPrincipalData, TupleData,
+        };
         use clarity::vm::Value;
         use hex::FromHex as _;
 
-        use crate::tools::{crosscheck, evaluate};
+        use crate::tools::{crosscheck, crosscheck_multi_contract, evaluate};
 
         #[test]
         fn to_consensus_buff_less_than_one_arg() {
@@ -306,6 +309,24 @@ mod tests {
         )
         }
 
+        #[test]
+        fn to_consensus_buff_tuple_keys_sorted() {
+            // Keys are serialized in lexicographic order, whatever the order
+            // in the source.
+            crosscheck(
+                "(to-consensus-buff? {b: 1, a: 2})",
+                Ok(Some(
+                    Value::some(Value::Sequence(SequenceData::Buffer(BuffData {
+                        data: Vec::from_hex(
+                            "0c000000020161000000000000000000000000000000000201620000000000000000000000000000000001",
+                        )
+                        .unwrap(),
+                    })))
+                    .unwrap(),
+                )),
+            )
+        }
+
         #[test]
         fn to_consensus_buff_string_utf8() {
             crosscheck(
@@ -373,6 +394,48 @@ mod tests {
             )
         }
 
+        #[test]
+        fn to_consensus_buff_buffer_adjacent_to_output() {
+            // The result of `concat` is the last value on the call stack, so the
+            // serialized output is written right after it.
+            crosscheck(
+                r#"(to-consensus-buff? (concat 0x1234 0x5678))"#,
+                Ok(Some(
+                    Value::some(Value::Sequence(SequenceData::Buffer(BuffData {
+                        data: Vec::from_hex("020000000412345678").unwrap(),
+                    })))
+                    .unwrap(),
+                )),
+            )
+        }
+
+        #[test]
+        fn to_consensus_buff_string_ascii_adjacent_to_output() {
+            crosscheck(
+                r#"(to-consensus-buff? (concat "Hello, " "World!"))"#,
+                Ok(Some(
+                    Value::some(Value::Sequence(SequenceData::Buffer(BuffData {
+                        data: Vec::from_hex("0d0000000d48656c6c6f2c20576f726c6421").unwrap(),
+                    })))
+                    .unwrap(),
+                )),
+            )
+        }
+
+        #[test]
+        fn to_consensus_buff_leaves_sources_intact() {
+            // Serializing the list must not overwrite the buffers it reads from.
+            crosscheck(
+                r#"
+(let ((a (concat 0x01 0x02)) (b (concat 0x03 0x04)))
+    {ser: (to-consensus-buff? (list a b)), a: a, b: b})
+"#,
+                evaluate(
+                    "{ser: (some 0x0b000000020200000002010202000000020304), a: 0x0102, b: 0x0304}",
+                ),
+            )
+        }
+
         #[test]
         fn to_consensus_buff_list() {
             crosscheck(r#"(to-consensus-buff? (list 1 2 3 4))"#,
@@ -385,6 +448,85 @@ mod tests {
         )
         }
 
+        #[test]
+        fn to_consensus_buff_list_of_buffers() {
+            crosscheck(
+                r#"(to-consensus-buff? (list 0x0102 0x03))"#,
+                Ok(Some(
+                    Value::some(Value::Sequence(SequenceData::Buffer(BuffData {
+                        data: Vec::from_hex("0b0000000202000000020102020000000103").unwrap(),
+                    })))
+                    .unwrap(),
+                )),
+            )
+        }
+
+        #[test]
+        fn to_consensus_buff_nested_list() {
+            crosscheck(r#"(to-consensus-buff? (list (list 1) (list 2 3)))"#,
+            Ok(Some(
+                Value::some(Value::Sequence(SequenceData::Buffer(BuffData {
+                    data: Vec::from_hex("0b000000020b0000000100000000000000000000000000000000010b0000000200000000000000000000000000000000020000000000000000000000000000000003").unwrap()
+                })))
+                .unwrap()
+            ))
+        )
+        }
+
+        #[test]
+        fn to_consensus_buff_empty_list() {
+            crosscheck(
+                r#"(to-consensus-buff? (list))"#,
+                Ok(Some(
+                    Value::some(Value::Sequence(SequenceData::Buffer(BuffData {
+                        data: Vec::from_hex("0b00000000").unwrap(),
+                    })))
+                    .unwrap(),
+                )),
+            )
+        }
+
+        #[test]
+        fn to_consensus_buff_trait_reference() {
+            let trait_contract = r#"
+(define-trait my-trait
+  ((add (int int) (response int int))))
+(define-public (add (a int) (b int))
+  (ok (+ a b))
+)
+            "#;
+
+            let caller_contract = r#"
+(use-trait the-trait .my-trait-contract.my-trait)
+(define-private (foo (adder <the-trait>))
+    (to-consensus-buff? adder)
+)
+(foo .my-trait-contract)
+            "#;
+
+            // A trait reference is serialized as the underlying contract principal.
+            let mut expected = vec![];
+            Value::Principal(PrincipalData::Contract(QualifiedContractIdentifier::new(
+                StandardPrincipalData::transient(),
+                "my-trait-contract".into(),
+            )))
+            .serialize_write(&mut expected)
+            .unwrap();
+
+            crosscheck_multi_contract(
+                &[
+                    ("my-trait-contract".into(), trait_contract),
+                    ("use-trait".into(), caller_contract),
+                ],
+                Ok(Some(
+                    Value::some(Value::Sequence(SequenceData::Buffer(BuffData {
+                        data: expected,
+                    })))
+                    .unwrap(),
+                )),
+            );
+        }
+
         //--- `from-consensus-buff?` tests
 
         #[test]
@@ -1194,5 +1336,48 @@ mod tests {
                 Ok(Some(Value::none())),
             )
         }
+
+        #[test]
+        fn from_consensus_buff_nested_round_trip() {
+            crosscheck(
+                "(from-consensus-buff? {a: (optional uint), b: (list 3 int)} (unwrap-panic (to-consensus-buff? {a: (some u1), b: (list 1 2 3)})))",
+                evaluate("(some {a: (some u1), b: (list 1 2 3)})"),
+            )
+        }
+
+        #[test]
+        fn from_consensus_buff_nested_round_trip_response() {
+            crosscheck(
+                "(from-consensus-buff? (list 2 (response {a: (optional uint)} int)) (unwrap-panic (to-consensus-buff? (list (ok {a: (some u1)}) (err -1)))))",
+                evaluate("(some (list (ok {a: (some u1)}) (err -1)))"),
+            )
+        }
+
+        #[test]
+        fn from_consensus_buff_nested_encoded() {
+            // ENCODED: {a: (some u1), b: (list 1 2 3)}
+            crosscheck(
+                "(from-consensus-buff? {a: (optional uint), b: (list 3 int)} 0x0c0000000201610a010000000000000000000000000000000101620b00000003000000000000000000000000000000000100000000000000000000000000000000020000000000000000000000000000000003)",
+                evaluate("(some {a: (some u1), b: (list 1 2 3)})"),
+            )
+        }
+
+        #[test]
+        fn from_consensus_buff_nested_short() {
+            // ENCODED: {a: (some u1), b: (list 1 2 3)} with the last byte missing
+            crosscheck(
+                "(from-consensus-buff? {a: (optional uint), b: (list 3 int)} 0x0c0000000201610a010000000000000000000000000000000101620b000000030000000000000000000000000000000001000000000000000000000000000000000200000000000000000000000000000000)",
+                Ok(Some(Value::none())),
+            )
+        }
+
+        #[test]
+        fn from_consensus_buff_nested_long() {
+            // ENCODED: {a: (some u1), b: (list 1 2 3)} with an extra trailing byte
+            crosscheck(
+                "(from-consensus-buff? {a: (optional uint), b: (list 3 int)} 0x0c0000000201610a010000000000000000000000000000000101620b0000000300000000000000000000000000000000010000000000000000000000000000000002000000000000000000000000000000000300)",
+                Ok(Some(Value::none())),
+            )
+        }
     }
 }
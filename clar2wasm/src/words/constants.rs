@@ -256,6 +256,26 @@ mod tests {
         );
     }
 
+    #[test]
+    fn test_tuple_constant() {
+        crosscheck(
+            r#"
+(define-constant config {name: "token", decimals: u6, admins: (list 'S1G2081040G2081040G2081040G208105NK8PE5)})
+(define-read-only (get-tuple-constant)
+  config)
+(define-read-only (get-tuple-field)
+  (get decimals config))
+{whole: (get-tuple-constant), field: (get-tuple-field)}
+"#,
+            evaluate(
+                r#"{
+  whole: {name: "token", decimals: u6, admins: (list 'S1G2081040G2081040G2081040G208105NK8PE5)},
+  field: u6
+}"#,
+            ),
+        );
+    }
+
     #[test]
     fn validate_define_const() {
         // Reserved keyword
@@ -118,8 +118,8 @@ impl ComplexWord for ContractCall {
             generator.traverse_expr(builder, contract_expr)?;
         }
 
-        // shadow args
-        let args = if args.len() >= 2 { &args[2..] } else { &[] };
+        // shadow args, skipping the contract and the function name
+        let args = args.get(2..).unwrap_or_default();
         let args_ty: Vec<_> = args
             .iter()
             .map(|arg| {
@@ -189,7 +189,7 @@ impl ComplexWord for ContractCall {
 mod tests {
     use clarity::vm::Value;
 
-    use crate::tools::{evaluate, TestEnvironment};
+    use crate::tools::{crosscheck_multi_contract, evaluate, TestEnvironment};
 
     #[test]
     fn as_contract_less_than_one_arg() {
@@ -233,6 +233,27 @@ mod tests {
             .contains("expecting >= 2 arguments, got 1"));
     }
 
+    #[test]
+    fn contract_call_no_params() {
+        crosscheck_multi_contract(
+            &[
+                ("callee".into(), "(define-public (no-params) (ok u42))"),
+                ("caller".into(), "(contract-call? .callee no-params)"),
+            ],
+            Ok(Some(Value::okay(Value::UInt(42)).unwrap())),
+        );
+    }
+
+    #[test]
+    fn contract_call_without_contract_or_function() {
+        let result = evaluate("(contract-call?)");
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("expecting >= 2 arguments, got 0"));
+    }
+
     #[test]
     fn static_no_args() {
         let mut env = TestEnvironment::default();
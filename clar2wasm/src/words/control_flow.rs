@@ -399,6 +399,36 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_unwrap_panic_in_memory_value() {
+        crosscheck(r#"(unwrap-panic (some "hello"))"#, evaluate(r#""hello""#));
+    }
+
+    #[test]
+    fn test_unwrap_err_panic_in_memory_value() {
+        crosscheck(
+            "(unwrap-err-panic (if true (err 0x0102) (ok u1)))",
+            evaluate("0x0102"),
+        );
+    }
+
+    #[test]
+    fn test_unwrap_err_panic_ok_from_public_function() {
+        let snippet = r#"
+(define-public (unwrap-res (x (response uint (string-ascii 8))))
+    (ok (unwrap-err-panic x))
+)
+(unwrap-res (ok u42))"#;
+
+        crosscheck(
+            snippet,
+            Err(Error::Runtime(
+                RuntimeErrorType::UnwrapFailure,
+                Some(Vec::new()),
+            )),
+        )
+    }
+
     /// Verify that the full response type is set correctly for the last
     /// expression in a `begin` block.
     #[test]
@@ -488,4 +518,51 @@ mod tests {
             evaluate("(ok 7)"),
         )
     }
+
+    #[test]
+    fn begin_drops_in_memory_intermediate_values() {
+        crosscheck(
+            r#"
+(define-private (simple)
+  (begin
+    0x0102030405
+    {a: "hello", b: (some 0x06)}
+    (+ 3 4)))
+
+(simple)
+"#,
+            Ok(Some(Value::Int(7))),
+        )
+    }
+
+    #[test]
+    fn begin_returns_in_memory_value() {
+        crosscheck(r#"(begin 42 0x0102 "last")"#, evaluate(r#""last""#))
+    }
+
+    #[test]
+    fn early_return_restores_stack_pointer() {
+        // Each call writes a 1KiB buffer to its frame before returning early
+        // through `asserts!`. If the frames were not released, the calls would
+        // run out of the contract's memory long before the end of the fold.
+        let big = "00".repeat(1024);
+        let items = (1..=1000)
+            .map(|i| i.to_string())
+            .collect::<Vec<_>>()
+            .join(" ");
+        let snippet = format!(
+            "
+(define-public (check (i int))
+  (let ((buf (concat 0x{big} (if (> i 0) 0x01 0x0203))))
+    (asserts! (< i 0) (err (len buf)))
+    (ok u0)))
+
+(define-private (step (i int) (acc uint))
+  (match (check i) ok-val acc err-val (+ acc err-val)))
+
+(fold step (list {items}) u0)
+"
+        );
+        crosscheck(&snippet, Ok(Some(Value::UInt(1000 * 1025))));
+    }
 }
@@ -356,6 +356,46 @@ mod tests {
         );
     }
 
+    #[test]
+    fn define_data_var_initial_value() {
+        crosscheck(
+            "
+(define-data-var n int 7)
+
+(define-read-only (get-n)
+  (var-get n))
+
+(get-n)
+",
+            Ok(Some(Value::Int(7))),
+        );
+    }
+
+    #[test]
+    fn test_var_get_tuple() {
+        crosscheck(
+            r#"
+(define-data-var config {name: (string-ascii 16), limits: (list 3 uint), owner: (optional principal)}
+    {name: "initial", limits: (list u1 u2 u3), owner: none})
+
+(define-public (update)
+  (begin
+    (var-set config {name: "updated", limits: (list u10), owner: (some tx-sender)})
+    (ok true)))
+
+(define-read-only (read)
+  (var-get config))
+
+(list (read) (begin (unwrap-panic (update)) (read)))
+"#,
+            evaluate(
+                r#"(list
+  {name: "initial", limits: (list u1 u2 u3), owner: none}
+  {name: "updated", limits: (list u10), owner: (some 'S1G2081040G2081040G2081040G208105NK8PE5)})"#,
+            ),
+        );
+    }
+
     #[test]
     fn validate_define_data_var() {
         // Reserved keyword
@@ -96,7 +96,9 @@ impl ComplexWord for DefaultTo {
 
 #[cfg(test)]
 mod tests {
-    use crate::tools::evaluate;
+    use clarity::vm::Value;
+
+    use crate::tools::{crosscheck, evaluate};
 
     #[test]
     fn default_to_less_than_two_args() {
@@ -117,4 +119,30 @@ mod tests {
             .to_string()
             .contains("expecting 2 arguments, got 3"));
     }
+
+    #[test]
+    fn default_to_some() {
+        crosscheck("(default-to 0 (some 5))", Ok(Some(Value::Int(5))));
+    }
+
+    #[test]
+    fn default_to_none() {
+        crosscheck("(default-to 0 none)", Ok(Some(Value::Int(0))));
+    }
+
+    #[test]
+    fn default_to_some_buffer() {
+        crosscheck(
+            "(default-to 0x00 (some 0x0102))",
+            Ok(Some(Value::buff_from(vec![1, 2]).unwrap())),
+        );
+    }
+
+    #[test]
+    fn default_to_none_buffer() {
+        crosscheck(
+            "(default-to 0x0102 (if true none (some 0x03)))",
+            Ok(Some(Value::buff_from(vec![1, 2]).unwrap())),
+        );
+    }
 }
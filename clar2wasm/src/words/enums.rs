@@ -134,7 +134,9 @@ impl ComplexWord for ClarityErr {
 
 #[cfg(test)]
 mod tests {
-    use crate::tools::evaluate;
+    use clarity::vm::Value;
+
+    use crate::tools::{crosscheck, evaluate};
 
     #[test]
     fn some_less_than_one_arg() {
@@ -195,4 +197,37 @@ mod tests {
             .to_string()
             .contains("expecting 1 arguments, got 2"));
     }
+
+    #[test]
+    fn some_read_back_with_match() {
+        crosscheck("(match (some 3) v (+ v 1) 0)", Ok(Some(Value::Int(4))));
+    }
+
+    #[test]
+    fn ok_and_err_read_back_with_match() {
+        crosscheck(
+            "
+(define-private (respond (success bool))
+  (if success (ok u5) (err u9)))
+(list
+  (match (respond true) v v e (+ e u100))
+  (match (respond false) v v e (+ e u100)))
+",
+            evaluate("(list u5 u109)"),
+        );
+    }
+
+    #[test]
+    fn ok_and_err_with_in_memory_arms() {
+        crosscheck(
+            r#"
+(define-private (respond (success bool))
+  (if success (ok "fine") (err 0x0bad)))
+(list
+  (match (respond true) v (len v) e (len e))
+  (match (respond false) v (len v) e (len e)))
+"#,
+            evaluate("(list u4 u2)"),
+        );
+    }
 }
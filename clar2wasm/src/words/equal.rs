@@ -1308,6 +1308,82 @@ mod tests {
         crosscheck(snippet, Ok(Some(clarity::vm::Value::Bool(true))));
     }
 
+    #[test]
+    fn is_eq_tuples() {
+        crosscheck(
+            r#"(is-eq {a: 1, b: "hello"} {a: 1, b: "hello"})"#,
+            Ok(Some(clarity::vm::Value::Bool(true))),
+        );
+        crosscheck(
+            r#"(is-eq {a: 1, b: "hello"} {a: 1, b: "world"})"#,
+            Ok(Some(clarity::vm::Value::Bool(false))),
+        );
+    }
+
+    #[test]
+    fn is_eq_three_equal_operands() {
+        crosscheck("(is-eq 1 1 1)", Ok(Some(Value::Bool(true))));
+    }
+
+    #[test]
+    fn is_eq_last_operand_differs() {
+        crosscheck("(is-eq 1 1 2)", Ok(Some(Value::Bool(false))));
+    }
+
+    #[test]
+    fn is_eq_middle_operand_differs() {
+        // Every operand is compared with the first one, not with its neighbours.
+        crosscheck("(is-eq 1 2 1)", Ok(Some(Value::Bool(false))));
+    }
+
+    #[test]
+    fn is_eq_many_in_memory_operands() {
+        crosscheck(
+            r#"(list (is-eq "a" "a" "a" "a") (is-eq "a" "a" "b" "a"))"#,
+            evaluate("(list true false)"),
+        );
+    }
+
+    #[test]
+    fn is_eq_nested_lists() {
+        crosscheck(
+            "(is-eq (list (list 1 2) (list 3)) (list (list 1 2) (list 3)))",
+            Ok(Some(clarity::vm::Value::Bool(true))),
+        );
+        crosscheck(
+            "(is-eq (list (list 1 2) (list 3)) (list (list 1) (list 2 3)))",
+            Ok(Some(clarity::vm::Value::Bool(false))),
+        );
+    }
+
+    #[test]
+    fn is_eq_optionals() {
+        crosscheck(
+            "(is-eq (some 0x0102) (some 0x0102))",
+            Ok(Some(clarity::vm::Value::Bool(true))),
+        );
+        crosscheck(
+            "(is-eq (some 0x0102) (some 0x01))",
+            Ok(Some(clarity::vm::Value::Bool(false))),
+        );
+        crosscheck(
+            "(is-eq (some 0x0102) none)",
+            Ok(Some(clarity::vm::Value::Bool(false))),
+        );
+    }
+
+    #[test]
+    fn is_eq_responses_with_nested_tuples() {
+        crosscheck(
+            "(is-eq (if true (ok {a: (some u1)}) (err u0)) (ok {a: (some u1)}))",
+            Ok(Some(clarity::vm::Value::Bool(true))),
+        );
+        crosscheck(
+            "(is-eq (if true (ok {a: (some u1)}) (err u0)) (err u0))",
+            Ok(Some(clarity::vm::Value::Bool(false))),
+        );
+    }
+
     #[test]
     fn index_of_complex_type() {
         crosscheck(
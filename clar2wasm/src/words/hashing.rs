@@ -267,6 +267,27 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_hash160() {
+        // hash160 is ripemd160(sha256(x)), not a single ripemd160.
+        let mut expected = [0u8; 20];
+        hex::decode_to_slice("b6a9c8c230722b7c748331a8b450f05566dc7d0f", &mut expected).unwrap();
+        crosscheck(
+            "(hash160 0x68656c6c6f)",
+            Ok(Some(Value::buff_from(expected.to_vec()).unwrap())),
+        );
+    }
+
+    #[test]
+    fn test_hash160_empty_buff() {
+        let mut expected = [0u8; 20];
+        hex::decode_to_slice("b472a266d0bd89c13706a4132ccfb16f7c3b9fcb", &mut expected).unwrap();
+        crosscheck(
+            "(hash160 0x)",
+            Ok(Some(Value::buff_from(expected.to_vec()).unwrap())),
+        );
+    }
+
     #[test]
     fn test_sha512() {
         let mut expected = [0u8; 64];
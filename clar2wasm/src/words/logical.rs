@@ -23,3 +23,35 @@ impl SimpleWord for Not {
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use clarity::vm::Value;
+
+    use crate::tools::{crosscheck, evaluate};
+
+    #[test]
+    fn not_true() {
+        crosscheck("(not true)", Ok(Some(Value::Bool(false))));
+    }
+
+    #[test]
+    fn not_false() {
+        crosscheck("(not false)", Ok(Some(Value::Bool(true))));
+    }
+
+    #[test]
+    fn not_expression() {
+        crosscheck("(not (> 1 2))", Ok(Some(Value::Bool(true))));
+    }
+
+    #[test]
+    fn not_more_than_one_arg() {
+        let result = evaluate("(not true false)");
+        assert!(result.is_err());
+        assert!(result
+            .unwrap_err()
+            .to_string()
+            .contains("expecting 1 arguments, got 2"));
+    }
+}
@@ -435,6 +435,87 @@ mod tests {
         crosscheck("(define-map approved-contracts principal bool) (map-insert approved-contracts tx-sender true) (map-delete approved-contracts tx-sender)", Ok(Some(Value::Bool(true))));
     }
 
+    #[test]
+    fn map_delete_twice() {
+        crosscheck(
+            "
+(define-map counters uint int)
+(map-insert counters u1 42)
+(list (map-delete counters u1) (map-delete counters u1))
+",
+            evaluate("(list true false)"),
+        );
+    }
+
+    #[test]
+    fn map_delete_never_set_key() {
+        crosscheck(
+            "(define-map counters uint int) (map-delete counters u1)",
+            Ok(Some(Value::Bool(false))),
+        );
+    }
+
+    #[test]
+    fn map_insert_existing_key() {
+        crosscheck(
+            "
+(define-map counters uint int)
+{a-first: (map-insert counters u1 42), b-second: (map-insert counters u1 43), c-value: (map-get? counters u1)}
+",
+            evaluate("{a-first: true, b-second: false, c-value: (some 42)}"),
+        );
+    }
+
+    #[test]
+    fn map_set_overwrites() {
+        crosscheck(
+            "
+(define-map counters uint int)
+{a-first: (map-set counters u1 42), b-second: (map-set counters u1 43), c-value: (map-get? counters u1)}
+",
+            evaluate("{a-first: true, b-second: true, c-value: (some 43)}"),
+        );
+    }
+
+    #[test]
+    fn map_get_hit_and_miss() {
+        crosscheck(
+            "
+(define-map counters uint int)
+(map-insert counters u1 42)
+(list (map-get? counters u1) (map-get? counters u2))
+",
+            evaluate("(list (some 42) none)"),
+        );
+    }
+
+    #[test]
+    fn map_get_after_delete() {
+        crosscheck(
+            "
+(define-map counters uint int)
+(map-insert counters u1 42)
+(map-delete counters u1)
+(map-get? counters u1)
+",
+            Ok(Some(Value::none())),
+        );
+    }
+
+    #[test]
+    fn map_tuple_key_and_value() {
+        crosscheck(
+            r#"
+(define-map profiles {owner: principal, id: uint} {name: (string-utf8 20), tags: (list 2 (buff 2)), score: (optional int)})
+(map-set profiles {owner: tx-sender, id: u1} {name: u"caf\u{e9}", tags: (list 0x0102 0x03), score: (some -5)})
+(list (map-get? profiles {owner: tx-sender, id: u1}) (map-get? profiles {owner: tx-sender, id: u2}))
+"#,
+            evaluate(
+                r#"(list (some {name: u"caf\u{e9}", tags: (list 0x0102 0x03), score: (some -5)}) none)"#,
+            ),
+        );
+    }
+
     #[test]
     fn map_define_set_get() {
         crosscheck("(define-map approved-contracts principal bool) (map-insert approved-contracts tx-sender true) (map-get? approved-contracts tx-sender)", Ok(Some(Value::some(Value::Bool(true)).unwrap())));
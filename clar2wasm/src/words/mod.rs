@@ -39,9 +39,20 @@ pub mod tokens;
 pub mod traits;
 pub mod tuples;
 
+/// A word that generates its own code for a call, including the traversal
+/// of its arguments.
+///
+/// The words of the standard library are listed in `COMPLEX_WORDS`. They can
+/// be replaced for a compilation with `CompileOptions::extra_words`.
 pub trait ComplexWord: Sync + core::fmt::Debug {
     fn name(&self) -> ClarityName;
 
+    /// Generates the code for a call to this word.
+    ///
+    /// `expr` is the whole call expression, which holds the type of the
+    /// result, and `args` are its arguments, not yet traversed. The word is
+    /// responsible for traversing the arguments it needs, and must leave the
+    /// result of the call on the stack.
     fn traverse(
         &self,
         generator: &mut WasmGenerator,
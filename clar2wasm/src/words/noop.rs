@@ -135,6 +135,38 @@ mod tests {
         crosscheck("(to-uint 0)", Ok(Some(Value::UInt(0))));
     }
 
+    #[test]
+    fn to_uint_max_int() {
+        crosscheck(
+            "(to-uint 170141183460469231731687303715884105727)",
+            Ok(Some(Value::UInt(i128::MAX as u128))),
+        );
+    }
+
+    #[test]
+    fn to_uint_minus_one() {
+        // Both 64-bit halves of -1 are all ones, so the sign bit of the high
+        // half is set.
+        crosscheck(
+            "(to-uint -1)",
+            Err(Error::Runtime(
+                RuntimeErrorType::ArithmeticUnderflow,
+                Some(Vec::new()),
+            )),
+        )
+    }
+
+    #[test]
+    fn to_int_max_uint() {
+        crosscheck(
+            "(to-int u340282366920938463463374607431768211455)",
+            Err(Error::Runtime(
+                RuntimeErrorType::ArithmeticOverflow,
+                Some(Vec::new()),
+            )),
+        )
+    }
+
     #[test]
     fn contract_of() {
         let mut env = TestEnvironment::default();
@@ -89,7 +89,9 @@ impl ComplexWord for IsNone {
 
 #[cfg(test)]
 mod tests {
-    use crate::tools::evaluate;
+    use clarity::vm::Value;
+
+    use crate::tools::{crosscheck, evaluate};
 
     #[test]
     fn test_is_some_no_args() {
@@ -130,4 +132,35 @@ mod tests {
             .to_string()
             .contains("expecting 1 arguments, got 2"));
     }
+
+    #[test]
+    fn is_some_true() {
+        crosscheck("(is-some (some 1))", Ok(Some(Value::Bool(true))));
+    }
+
+    #[test]
+    fn is_some_false() {
+        crosscheck(
+            "(is-some (if true none (some 1)))",
+            Ok(Some(Value::Bool(false))),
+        );
+    }
+
+    #[test]
+    fn is_some_in_memory_value() {
+        crosscheck(r#"(is-some (some "hello"))"#, Ok(Some(Value::Bool(true))));
+    }
+
+    #[test]
+    fn is_none_true() {
+        crosscheck(
+            "(is-none (if true none (some 0x0102)))",
+            Ok(Some(Value::Bool(true))),
+        );
+    }
+
+    #[test]
+    fn is_none_false() {
+        crosscheck("(is-none (some 0x0102))", Ok(Some(Value::Bool(false))));
+    }
 }
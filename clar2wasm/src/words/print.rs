@@ -87,10 +87,11 @@ impl ComplexWord for Print {
 #[cfg(test)]
 mod tests {
     use clarity::types::StacksEpochId;
+    use clarity::vm::events::StacksTransactionEvent;
     use clarity::vm::types::{ListTypeData, TupleData};
     use clarity::vm::Value;
 
-    use crate::tools::{crosscheck, evaluate};
+    use crate::tools::{crosscheck, evaluate, TestEnvironment};
 
     #[test]
     fn print_no_args() {
@@ -117,6 +118,33 @@ mod tests {
         crosscheck("(print 42)", Ok(Some(Value::Int(42))));
     }
 
+    #[test]
+    fn test_print_event() {
+        let mut env = TestEnvironment::default();
+        let result = env
+            .evaluate("(print u5)")
+            .expect("Failed to init contract.");
+        assert_eq!(result, Some(Value::UInt(5)));
+
+        let events: Vec<_> = env
+            .get_events()
+            .iter()
+            .flat_map(|batch| batch.events.iter())
+            .collect();
+        assert_eq!(events.len(), 1);
+
+        let StacksTransactionEvent::SmartContractEvent(event) = events[0] else {
+            panic!("Unexpected event: {:?}", events[0]);
+        };
+        assert_eq!(event.key.1, "print");
+
+        let mut payload = vec![];
+        event.value.serialize_write(&mut payload).unwrap();
+        let mut expected = vec![0x01];
+        expected.extend_from_slice(&5u128.to_be_bytes());
+        assert_eq!(payload, expected);
+    }
+
     #[test]
     fn test_contract_call() {
         let first_contract_name = "callee".into();
@@ -91,7 +91,9 @@ impl ComplexWord for IsErr {
 
 #[cfg(test)]
 mod tests {
-    use crate::tools::evaluate;
+    use clarity::vm::Value;
+
+    use crate::tools::{crosscheck, evaluate};
 
     #[test]
     fn test_is_ok_no_args() {
@@ -132,4 +134,36 @@ mod tests {
             .to_string()
             .contains("expecting 1 arguments, got 2"));
     }
+
+    #[test]
+    fn is_ok_true() {
+        crosscheck(
+            "(is-ok (if true (ok 1) (err u1)))",
+            Ok(Some(Value::Bool(true))),
+        );
+    }
+
+    #[test]
+    fn is_ok_false() {
+        crosscheck(
+            r#"(is-ok (if true (err "failure") (ok 1)))"#,
+            Ok(Some(Value::Bool(false))),
+        );
+    }
+
+    #[test]
+    fn is_err_true() {
+        crosscheck(
+            r#"(is-err (if true (err "failure") (ok 1)))"#,
+            Ok(Some(Value::Bool(true))),
+        );
+    }
+
+    #[test]
+    fn is_err_false() {
+        crosscheck(
+            "(is-err (if true (ok 0x0102) (err u1)))",
+            Ok(Some(Value::Bool(false))),
+        );
+    }
 }
@@ -304,10 +304,11 @@ impl ComplexWord for Append {
         let memory = generator.get_memory()?;
 
         // Allocate stack space for the new list.
-        let (write_ptr, length) = generator.create_call_stack_local(builder, &ty, false, true);
+        let (write_ptr, _) = generator.create_call_stack_local(builder, &ty, false, true);
 
-        // Push the offset and length of this list to the stack to be returned.
-        builder.local_get(write_ptr).i32_const(length);
+        // Push the offset of this list to the stack to be returned. The
+        // length is only known once the source list has been traversed.
+        builder.local_get(write_ptr);
 
         // Push the write pointer onto the stack for `memory.copy`.
         builder.local_get(write_ptr);
@@ -340,7 +341,15 @@ impl ComplexWord for Append {
             .clone();
 
         // Store the element at the write pointer.
-        generator.write_to_memory(builder, write_ptr, 0, &elem_ty)?;
+        let elem_size = generator.write_to_memory(builder, write_ptr, 0, &elem_ty)?;
+
+        // The length of the new list is the length of the source list plus
+        // the size of the appended element. The source list is not necessarily
+        // at its maximum length, so we can't use the size of the result type.
+        builder
+            .local_get(src_length)
+            .i32_const(elem_size as i32)
+            .binop(BinaryOp::I32Add);
 
         Ok(())
     }
@@ -840,7 +849,7 @@ impl ComplexWord for Len {
         // Get the length
         generator
             .get_expr_type(seq)
-            .ok_or_else(|| GeneratorError::TypeError("append result must be typed".to_string()))
+            .ok_or_else(|| GeneratorError::TypeError("len argument must be typed".to_string()))
             .and_then(|ty| match ty {
                 TypeSignature::SequenceType(SequenceSubtype::ListType(list)) => {
                     // The length of the list in bytes is on the top of the stack. If we
@@ -1589,6 +1598,47 @@ mod tests {
 
     use crate::tools::{crosscheck, crosscheck_compare_only, evaluate};
 
+    #[test]
+    fn list_cons_ints() {
+        crosscheck(
+            "(list 1 2 3)",
+            Ok(Some(
+                Value::cons_list_unsanitized(vec![Value::Int(1), Value::Int(2), Value::Int(3)])
+                    .unwrap(),
+            )),
+        );
+    }
+
+    #[test]
+    fn list_cons_list_of_lists() {
+        crosscheck(
+            "(list (list 1) (list 2 3))",
+            Ok(Some(
+                Value::cons_list_unsanitized(vec![
+                    Value::cons_list_unsanitized(vec![Value::Int(1)]).unwrap(),
+                    Value::cons_list_unsanitized(vec![Value::Int(2), Value::Int(3)]).unwrap(),
+                ])
+                .unwrap(),
+            )),
+        );
+    }
+
+    #[test]
+    fn list_cons_unifiable_types() {
+        crosscheck(
+            "(list (some 1) none (some 3))",
+            evaluate("(list (some 1) none (some 3))"),
+        );
+    }
+
+    #[test]
+    fn list_cons_in_memory_elements() {
+        crosscheck(
+            r#"(list "hello" "" "world")"#,
+            evaluate(r#"(list "hello" "" "world")"#),
+        );
+    }
+
     #[test]
     fn fold_less_than_three_args() {
         let result = evaluate("(fold + (list 1 2 3))");
@@ -1699,6 +1749,38 @@ mod tests {
             .contains("expecting 1 arguments, got 2"));
     }
 
+    #[test]
+    fn len_list() {
+        crosscheck("(len (list 1 2 3 4))", Ok(Some(Value::UInt(4))));
+    }
+
+    #[test]
+    fn len_list_of_in_memory_elements() {
+        crosscheck(
+            r#"(len (list "hello" "world" "!"))"#,
+            Ok(Some(Value::UInt(3))),
+        );
+    }
+
+    #[test]
+    fn len_buffer() {
+        crosscheck("(len 0x0102030405)", Ok(Some(Value::UInt(5))));
+    }
+
+    #[test]
+    fn len_string_ascii() {
+        crosscheck(r#"(len "hello")"#, Ok(Some(Value::UInt(5))));
+    }
+
+    #[test]
+    fn len_string_utf8_multibyte() {
+        // 4 characters, out of which 3 are encoded on multiple bytes.
+        crosscheck(
+            r#"(len u"a\u{0141}\u{20AC}\u{1F98A}")"#,
+            Ok(Some(Value::UInt(4))),
+        );
+    }
+
     #[test]
     fn element_at_less_than_two_args() {
         let result = evaluate("(element-at? (list 1 2 3))");
@@ -1978,6 +2060,26 @@ mod tests {
         )
     }
 
+    #[test]
+    fn test_map_string_utf8_multi_byte() {
+        crosscheck(
+            r#"
+(define-private (is-fox (char (string-utf8 1))) (is-eq char u"\u{1F98A}"))
+(define-private (same (char (string-utf8 1))) char)
+{
+    fox: (map is-fox u"a\u{1F98A}\u{0141}"),
+    chars: (map same u"a\u{1F98A}\u{0141}")
+}
+        "#,
+            evaluate(
+                r#"{
+    fox: (list false true false),
+    chars: (list u"a" u"\u{1F98A}" u"\u{0141}")
+}"#,
+            ),
+        )
+    }
+
     #[test]
     fn test_map() {
         const MAP_FNS: &str = "
@@ -2188,6 +2290,45 @@ mod tests {
         )
     }
 
+    #[test]
+    fn fold_buffer() {
+        crosscheck(
+            "
+(define-private (prepend-byte (byte (buff 1)) (acc (buff 4)))
+    (unwrap-panic (as-max-len? (concat byte acc) u4)))
+
+(fold prepend-byte 0x01020304 0x)
+",
+            evaluate("0x04030201"),
+        )
+    }
+
+    #[test]
+    fn fold_string_ascii() {
+        crosscheck(
+            r#"
+(define-private (prepend-char (char (string-ascii 1)) (acc (string-ascii 3)))
+    (unwrap-panic (as-max-len? (concat char acc) u3)))
+
+(fold prepend-char "abc" "")
+"#,
+            evaluate(r#""cba""#),
+        )
+    }
+
+    #[test]
+    fn fold_string_utf8() {
+        crosscheck(
+            r#"
+(define-private (prepend-char (char (string-utf8 1)) (acc (string-utf8 3)))
+    (unwrap-panic (as-max-len? (concat char acc) u3)))
+
+(fold prepend-char u"a\u{0141}\u{1F98A}" u"")
+"#,
+            evaluate(r#"u"\u{1F98A}\u{0141}a""#),
+        )
+    }
+
     #[test]
     fn as_max_len_list() {
         crosscheck(
@@ -2221,6 +2362,46 @@ mod tests {
         )
     }
 
+    #[test]
+    fn as_max_len_list_under_limit() {
+        crosscheck(
+            "(as-max-len? (list 1 2 3) u5)",
+            evaluate("(some (list 1 2 3))"),
+        )
+    }
+
+    #[test]
+    fn as_max_len_list_over_limit() {
+        crosscheck("(as-max-len? (list 1 2 3) u2)", Ok(Some(Value::none())))
+    }
+
+    #[test]
+    fn as_max_len_buffer_at_limit() {
+        crosscheck("(as-max-len? 0x010203 u3)", evaluate("(some 0x010203)"))
+    }
+
+    #[test]
+    fn as_max_len_buffer_over_limit() {
+        crosscheck("(as-max-len? 0x010203 u2)", Ok(Some(Value::none())))
+    }
+
+    #[test]
+    fn as_max_len_string_utf8_multibyte_at_limit() {
+        // 3 characters, but 12 bytes in memory: the limit applies to characters.
+        crosscheck(
+            r#"(as-max-len? u"a\u{0141}\u{1F98A}" u3)"#,
+            evaluate(r#"(some u"a\u{0141}\u{1F98A}")"#),
+        )
+    }
+
+    #[test]
+    fn as_max_len_string_utf8_multibyte_over_limit() {
+        crosscheck(
+            r#"(as-max-len? u"a\u{0141}\u{1F98A}" u2)"#,
+            Ok(Some(Value::none())),
+        )
+    }
+
     #[test]
     fn fold_bench() {
         crosscheck(
@@ -2286,6 +2467,59 @@ mod tests {
         );
     }
 
+    #[test]
+    fn append_int() {
+        crosscheck(
+            "(append (list 1 2 3) 4)",
+            Ok(Some(
+                Value::cons_list_unsanitized(vec![
+                    Value::Int(1),
+                    Value::Int(2),
+                    Value::Int(3),
+                    Value::Int(4),
+                ])
+                .unwrap(),
+            )),
+        )
+    }
+
+    #[test]
+    fn append_buffer() {
+        crosscheck(
+            "(append (list 0x01 0x0203) 0x040506)",
+            evaluate("(list 0x01 0x0203 0x040506)"),
+        )
+    }
+
+    #[test]
+    fn append_to_list_shorter_than_max_len() {
+        crosscheck(
+            "
+(define-private (add-one (l (list 5 int)))
+    (append l 42))
+
+(add-one (list 1 2))
+",
+            Ok(Some(
+                Value::cons_list_unsanitized(vec![Value::Int(1), Value::Int(2), Value::Int(42)])
+                    .unwrap(),
+            )),
+        )
+    }
+
+    #[test]
+    fn append_buffer_to_list_shorter_than_max_len() {
+        crosscheck(
+            "
+(define-private (add-buff (l (list 5 (buff 2))))
+    (append l 0xffff))
+
+(add-buff (list 0x01))
+",
+            evaluate("(list 0x01 0xffff)"),
+        )
+    }
+
     #[test]
     fn double_append() {
         let snippet = "(append (append (list 1) 2) 3)";
@@ -2297,6 +2531,75 @@ mod tests {
         crosscheck(snippet, Ok(Some(expected)))
     }
 
+    #[test]
+    fn concat_buffers() {
+        crosscheck(
+            "(concat 0x0102 0x030405)",
+            Ok(Some(Value::buff_from(vec![1, 2, 3, 4, 5]).unwrap())),
+        )
+    }
+
+    #[test]
+    fn concat_string_ascii() {
+        crosscheck(
+            r#"(concat "hello " "world")"#,
+            Ok(Some(
+                Value::string_ascii_from_bytes(b"hello world".to_vec()).unwrap(),
+            )),
+        )
+    }
+
+    #[test]
+    fn concat_string_utf8() {
+        crosscheck(
+            r#"(concat u"hel\u{0141}o " u"\u{1F98A}")"#,
+            evaluate(r#"u"hel\u{0141}o \u{1F98A}""#),
+        )
+    }
+
+    #[test]
+    fn concat_lists() {
+        crosscheck(
+            "(concat (list 1 2) (list 3))",
+            Ok(Some(
+                Value::cons_list_unsanitized(vec![Value::Int(1), Value::Int(2), Value::Int(3)])
+                    .unwrap(),
+            )),
+        )
+    }
+
+    #[test]
+    fn concat_empty_list_first() {
+        crosscheck("(concat (list) (list 1 2))", evaluate("(list 1 2)"))
+    }
+
+    #[test]
+    fn concat_empty_list_last() {
+        crosscheck("(concat (list 1 2) (list))", evaluate("(list 1 2)"))
+    }
+
+    #[test]
+    fn concat_empty_buffers() {
+        crosscheck(
+            "(concat 0x 0x)",
+            Ok(Some(Value::buff_from(vec![]).unwrap())),
+        )
+    }
+
+    #[test]
+    fn concat_empty_buffer_with_buffer() {
+        crosscheck("(concat 0x 0x0102)", evaluate("0x0102"));
+        crosscheck("(concat 0x0102 0x)", evaluate("0x0102"));
+    }
+
+    #[test]
+    fn concat_lists_of_in_memory_elements() {
+        crosscheck(
+            r#"(concat (list "a" "bc") (list "def"))"#,
+            evaluate(r#"(list "a" "bc" "def")"#),
+        )
+    }
+
     #[test]
     fn unit_fold_repsonses_full_type() {
         let snippet = "
@@ -2487,6 +2790,86 @@ mod tests {
                 )),
             )
         }
+
+        #[test]
+        fn replace_at_last_element() {
+            crosscheck(
+                "(replace-at? (list 1 2 3) u2 4)",
+                evaluate("(some (list 1 2 4))"),
+            );
+            crosscheck(
+                "(replace-at? 0x010203 u2 0x04)",
+                evaluate("(some 0x010204)"),
+            );
+            crosscheck(r#"(replace-at? "abc" u2 "d")"#, evaluate(r#"(some "abd")"#));
+            crosscheck(
+                r#"(replace-at? u"ab\u{1F98A}" u2 u"c")"#,
+                evaluate(r#"(some u"abc")"#),
+            );
+        }
+
+        #[test]
+        fn replace_at_length_is_none() {
+            crosscheck("(replace-at? (list 1 2 3) u3 4)", evaluate("none"));
+            crosscheck("(replace-at? 0x010203 u3 0x04)", evaluate("none"));
+            crosscheck(r#"(replace-at? "abc" u3 "d")"#, evaluate("none"));
+            crosscheck(r#"(replace-at? u"abc" u3 u"d")"#, evaluate("none"));
+        }
+
+        #[test]
+        fn replace_at_large_index_is_none() {
+            // The upper 64 bits of these indices are set, so they must not be
+            // truncated before the bounds check.
+            let index = u128::MAX;
+            crosscheck(
+                &format!("(replace-at? (list 1 2 3) u{index} 4)"),
+                evaluate("none"),
+            );
+            crosscheck(
+                &format!("(replace-at? 0x010203 u{index} 0x04)"),
+                evaluate("none"),
+            );
+            let index = 1u128 << 64;
+            crosscheck(
+                &format!(r#"(replace-at? "abc" u{index} "d")"#),
+                evaluate("none"),
+            );
+            crosscheck(
+                &format!(r#"(replace-at? u"abc" u{index} u"d")"#),
+                evaluate("none"),
+            );
+        }
+
+        #[test]
+        fn element_at_max_index_is_none() {
+            crosscheck(
+                "(element-at? (list 1 2 3) u340282366920938463463374607431768211455)",
+                evaluate("none"),
+            );
+            crosscheck(
+                "(element-at? 0x010203 u340282366920938463463374607431768211455)",
+                evaluate("none"),
+            );
+            crosscheck(
+                r#"(element-at? u"abc" u340282366920938463463374607431768211455)"#,
+                evaluate("none"),
+            );
+        }
+
+        #[test]
+        fn element_at_index_with_upper_bits_is_none() {
+            // The lower 64 bits of this index are `1`, a valid index if the
+            // upper bits were ignored.
+            let index = (1u128 << 64) + 1;
+            crosscheck(
+                &format!("(element-at? (list 1 2 3) u{index})"),
+                evaluate("none"),
+            );
+            crosscheck(
+                &format!(r#"(element-at? "abc" u{index})"#),
+                evaluate("none"),
+            );
+        }
         #[test]
         fn map_bit_and() {
             let a = "(map bit-and (list 1 2 3) (list 1 7 6) (list 1 15 15))";
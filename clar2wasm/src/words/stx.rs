@@ -135,7 +135,26 @@ impl SimpleWord for StxGetAccount {
 
 #[cfg(test)]
 mod tests {
-    use crate::tools::{crosscheck, evaluate};
+    use clarity::vm::events::{STXEventType, StacksTransactionEvent};
+    use clarity::vm::types::{BuffData, PrincipalData};
+
+    use crate::tools::{crosscheck, evaluate, TestEnvironment};
+
+    /// Runs `snippet` and returns the memo of the STX transfer event it emitted.
+    fn stx_transfer_event_memo(snippet: &str) -> BuffData {
+        let mut env = TestEnvironment::default();
+        env.evaluate(snippet).expect("Failed to init contract.");
+        env.get_events()
+            .iter()
+            .flat_map(|batch| batch.events.iter())
+            .find_map(|event| match event {
+                StacksTransactionEvent::STXEvent(STXEventType::STXTransferEvent(data)) => {
+                    Some(data.memo.clone())
+                }
+                _ => None,
+            })
+            .expect("No STX transfer event emitted")
+    }
 
     #[test]
     fn stx_transfer_less_than_three_args() {
@@ -190,6 +209,25 @@ mod tests {
         )
     }
 
+    #[test]
+    fn stx_get_balance_credited_account() {
+        // The sender is credited 1_000_000_000 uSTX when the test environment is set up.
+        crosscheck(
+            "
+(define-public (test-stx-get-balance)
+  (begin
+    (unwrap-panic (stx-transfer? u500 tx-sender 'ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM))
+    (ok {
+      sender: (stx-get-balance tx-sender),
+      recipient: (stx-get-balance 'ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM)
+    })))
+
+(test-stx-get-balance)
+",
+            evaluate("(ok {sender: u999999500, recipient: u500})"),
+        )
+    }
+
     #[test]
     fn stx_test_burn_ok() {
         crosscheck(
@@ -225,6 +263,18 @@ mod tests {
         )
     }
 
+    #[test]
+    fn stx_burn_decreases_liquid_supply() {
+        crosscheck(
+            "
+(let ((before stx-liquid-supply))
+  (unwrap-panic (stx-burn? u100 tx-sender))
+  {before: before, after: stx-liquid-supply, balance: (stx-get-balance tx-sender)})
+",
+            evaluate("{before: u1000000000, after: u999999900, balance: u999999900}"),
+        )
+    }
+
     #[test]
     fn stx_transfer_ok() {
         //
@@ -234,6 +284,44 @@ mod tests {
         )
     }
 
+    #[test]
+    fn stx_transfer_records_one_event() {
+        let mut env = TestEnvironment::default();
+        env.evaluate(
+            "(stx-transfer? u100 'S1G2081040G2081040G2081040G208105NK8PE5 'ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM)",
+        )
+        .expect("Failed to init contract.");
+
+        let events: Vec<_> = env
+            .get_events()
+            .iter()
+            .flat_map(|batch| batch.events.iter())
+            .collect();
+        assert_eq!(events.len(), 1);
+
+        let StacksTransactionEvent::STXEvent(STXEventType::STXTransferEvent(data)) = events[0]
+        else {
+            panic!("Expected an STX transfer event, got {:?}", events[0]);
+        };
+        assert_eq!(data.amount, 100);
+        assert_eq!(
+            data.sender,
+            PrincipalData::parse("S1G2081040G2081040G2081040G208105NK8PE5").unwrap()
+        );
+        assert_eq!(
+            data.recipient,
+            PrincipalData::parse("ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM").unwrap()
+        );
+    }
+
+    #[test]
+    fn stx_transfer_event_has_no_memo() {
+        let memo = stx_transfer_event_memo(
+            "(stx-transfer? u100 'S1G2081040G2081040G2081040G208105NK8PE5 'ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM)",
+        );
+        assert!(memo.data.is_empty());
+    }
+
     #[test]
     fn stx_transfer_err_1() {
         // not enough balance
@@ -267,6 +355,14 @@ mod tests {
         )
     }
 
+    #[test]
+    fn stx_transfer_self_leaves_balance_unchanged() {
+        crosscheck(
+            "{transfer: (stx-transfer? u100 tx-sender tx-sender), balance: (stx-get-balance tx-sender)}",
+            evaluate("{transfer: (err u2), balance: u1000000000}"),
+        )
+    }
+
     //
     // Module with tests that should only be executed
     // when running Clarity::V2 or Clarity::v3.
@@ -301,6 +397,49 @@ mod tests {
             )
         }
 
+        #[test]
+        fn stx_account_with_balance() {
+            crosscheck(
+                "(stx-account tx-sender)",
+                evaluate("{locked: u0, unlock-height: u0, unlocked: u1000000000}"),
+            )
+        }
+
+        #[test]
+        fn stx_account_after_transfer() {
+            crosscheck(
+                "
+(unwrap-panic (stx-transfer? u100 tx-sender 'ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM))
+(list (stx-account tx-sender) (stx-account 'ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM))
+",
+                evaluate(
+                    "(list
+  {locked: u0, unlock-height: u0, unlocked: u999999900}
+  {locked: u0, unlock-height: u0, unlocked: u100})",
+                ),
+            )
+        }
+
+        #[test]
+        fn stx_account_with_locked_balance() {
+            use clarity::vm::types::StandardPrincipalData;
+
+            use crate::tools::{crosscheck_with_env, TestConfig};
+
+            let mut env =
+                TestEnvironment::new(TestConfig::latest_epoch(), TestConfig::clarity_version());
+            env.lock_stx(
+                &PrincipalData::Standard(StandardPrincipalData::transient()),
+                400_000_000,
+                1000,
+            );
+            crosscheck_with_env(
+                "(stx-account tx-sender)",
+                evaluate("{locked: u400000000, unlock-height: u1000, unlocked: u600000000}"),
+                env,
+            )
+        }
+
         #[test]
         fn stx_transfer_memo_ok() {
             //
@@ -309,5 +448,49 @@ mod tests {
                 evaluate("(ok true)"),
             )
         }
+
+        #[test]
+        fn stx_transfer_memo_event_has_memo() {
+            let memo = stx_transfer_event_memo(
+                "(stx-transfer-memo? u100 'S1G2081040G2081040G2081040G208105NK8PE5 'ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM 0x12345678)",
+            );
+            assert_eq!(memo.data, vec![0x12, 0x34, 0x56, 0x78]);
+        }
+
+        #[test]
+        fn stx_transfer_memo_err_1() {
+            // not enough balance
+            crosscheck(
+                "(stx-transfer-memo? u5000000000 tx-sender 'ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM 0x12345678)",
+                evaluate("(err u1)"),
+            )
+        }
+
+        #[test]
+        fn stx_transfer_memo_err_2() {
+            // sender is recipient
+            crosscheck(
+                "(stx-transfer-memo? u100 tx-sender tx-sender 0x12345678)",
+                evaluate("(err u2)"),
+            )
+        }
+
+        #[test]
+        fn stx_transfer_memo_err_3() {
+            // non-positive amount
+            crosscheck(
+                "(stx-transfer-memo? u0 tx-sender 'ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM 0x12345678)",
+                evaluate("(err u3)"),
+            )
+        }
+
+        #[test]
+        fn stx_transfer_memo_err_4() {
+            // sender is not tx-sender
+            crosscheck(
+                "(stx-transfer-memo? u100 'ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM tx-sender 0x12345678)",
+                evaluate("(err u4)"),
+            )
+        }
     }
 }
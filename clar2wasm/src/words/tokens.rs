@@ -537,10 +537,14 @@ impl ComplexWord for GetOwnerOfNonFungibleToken {
 
 #[cfg(test)]
 mod tests {
+    use clarity::vm::errors::{Error, RuntimeErrorType};
     use clarity::vm::types::{PrincipalData, TupleData};
     use clarity::vm::Value;
 
-    use crate::tools::{crosscheck, crosscheck_expect_failure, evaluate};
+    use crate::tools::{
+        crosscheck, crosscheck_compare_only_with_expected_error, crosscheck_expect_failure,
+        evaluate,
+    };
 
     //
     // Module with tests that should only be executed
@@ -815,6 +819,192 @@ mod tests {
         crosscheck_expect_failure(ERR);
     }
 
+    #[test]
+    fn ft_mint_up_to_supply_cap() {
+        crosscheck(
+            "(define-fungible-token capped u100)
+             (unwrap-panic (ft-mint? capped u60 tx-sender))
+             (unwrap-panic (ft-mint? capped u40 'ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM))
+             (list (ft-get-supply capped) (ft-get-balance capped tx-sender))",
+            evaluate("(list u100 u60)"),
+        )
+    }
+
+    #[test]
+    fn ft_mint_over_supply_cap() {
+        crosscheck_compare_only_with_expected_error(
+            "(define-fungible-token capped u100)
+             (unwrap-panic (ft-mint? capped u60 tx-sender))
+             (ft-mint? capped u41 tx-sender)",
+            |e| {
+                matches!(
+                    e,
+                    Error::Runtime(RuntimeErrorType::SupplyOverflow(101, 100), _)
+                )
+            },
+        )
+    }
+
+    #[test]
+    fn ft_supply_cap_from_expression() {
+        crosscheck_compare_only_with_expected_error(
+            "(define-fungible-token capped (+ u50 u50))
+             (unwrap-panic (ft-mint? capped u100 tx-sender))
+             (ft-mint? capped u1 tx-sender)",
+            |e| {
+                matches!(
+                    e,
+                    Error::Runtime(RuntimeErrorType::SupplyOverflow(101, 100), _)
+                )
+            },
+        )
+    }
+
+    #[test]
+    fn ft_get_balance_never_held() {
+        crosscheck(
+            "(define-fungible-token foo)
+             (unwrap-panic (ft-mint? foo u10 tx-sender))
+             (ft-get-balance foo 'ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM)",
+            Ok(Some(Value::UInt(0))),
+        )
+    }
+
+    #[test]
+    fn ft_get_supply_after_mint_and_burn() {
+        crosscheck(
+            "(define-fungible-token foo)
+             (unwrap-panic (ft-mint? foo u10 tx-sender))
+             (unwrap-panic (ft-mint? foo u5 'ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM))
+             (unwrap-panic (ft-burn? foo u3 tx-sender))
+             (list (ft-get-supply foo) (ft-get-balance foo tx-sender))",
+            evaluate("(list u12 u7)"),
+        )
+    }
+
+    #[test]
+    fn ft_burn_full_balance() {
+        crosscheck(
+            "(define-fungible-token foo)
+             (unwrap-panic (ft-mint? foo u10 tx-sender))
+             {
+                burn: (ft-burn? foo u10 tx-sender),
+                balance: (ft-get-balance foo tx-sender),
+                supply: (ft-get-supply foo),
+                again: (ft-burn? foo u1 tx-sender)
+             }",
+            evaluate("{burn: (ok true), balance: u0, supply: u0, again: (err u1)}"),
+        )
+    }
+
+    #[test]
+    fn nft_tuple_identifier_mint_transfer_get_owner() {
+        crosscheck(
+            "(define-non-fungible-token ticket {event: (string-ascii 16), seat: uint})
+             (unwrap-panic (nft-mint? ticket {event: \"concert\", seat: u1} tx-sender))
+             (unwrap-panic (nft-mint? ticket {event: \"concert\", seat: u2} tx-sender))
+             (unwrap-panic (nft-transfer? ticket {event: \"concert\", seat: u1} tx-sender 'ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM))
+             (list
+                (nft-get-owner? ticket {event: \"concert\", seat: u1})
+                (nft-get-owner? ticket {event: \"concert\", seat: u2})
+                (nft-get-owner? ticket {event: \"concert\", seat: u3}))",
+            evaluate(
+                "(list (some 'ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM) (some 'S1G2081040G2081040G2081040G208105NK8PE5) none)",
+            ),
+        )
+    }
+
+    #[test]
+    fn nft_tuple_identifier_mint_duplicate() {
+        crosscheck(
+            "(define-non-fungible-token ticket {event: (string-ascii 16), seat: uint})
+             (unwrap-panic (nft-mint? ticket {event: \"concert\", seat: u1} tx-sender))
+             (nft-mint? ticket {event: \"concert\", seat: u1} 'ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM)",
+            evaluate("(err u1)"),
+        )
+    }
+
+    #[test]
+    fn nft_tuple_identifier_transfer_not_owner() {
+        crosscheck(
+            "(define-non-fungible-token ticket {event: (string-ascii 16), seat: uint})
+             (unwrap-panic (nft-mint? ticket {event: \"concert\", seat: u1} 'ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM))
+             (nft-transfer? ticket {event: \"concert\", seat: u1} tx-sender 'ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM)",
+            evaluate("(err u1)"),
+        )
+    }
+
+    #[test]
+    fn nft_tuple_identifier_burn() {
+        crosscheck(
+            "(define-non-fungible-token ticket {event: (string-ascii 16), seat: uint})
+             (unwrap-panic (nft-mint? ticket {event: \"concert\", seat: u1} tx-sender))
+             {
+                attempt: (nft-burn? ticket {event: \"concert\", seat: u1} 'ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM),
+                burn: (nft-burn? ticket {event: \"concert\", seat: u1} tx-sender),
+                owner: (nft-get-owner? ticket {event: \"concert\", seat: u1}),
+             }",
+            evaluate("{attempt: (err u1), burn: (ok true), owner: none}"),
+        )
+    }
+
+    #[test]
+    fn ft_transfer_ok() {
+        crosscheck(
+            "(define-fungible-token foo)
+             (unwrap-panic (ft-mint? foo u1000 tx-sender))
+             (ft-transfer? foo u10 tx-sender 'ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM)",
+            evaluate("(ok true)"),
+        )
+    }
+
+    #[test]
+    fn ft_transfer_updates_balances() {
+        crosscheck(
+            "(define-fungible-token foo)
+             (unwrap-panic (ft-mint? foo u1000 tx-sender))
+             (unwrap-panic (ft-transfer? foo u10 tx-sender 'ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM))
+             (list
+                (ft-get-balance foo tx-sender)
+                (ft-get-balance foo 'ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM)
+                (ft-get-supply foo))",
+            evaluate("(list u990 u10 u1000)"),
+        )
+    }
+
+    #[test]
+    fn ft_transfer_err_1() {
+        // not enough balance
+        crosscheck(
+            "(define-fungible-token foo)
+             (unwrap-panic (ft-mint? foo u1000 tx-sender))
+             (ft-transfer? foo u1001 tx-sender 'ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM)",
+            evaluate("(err u1)"),
+        )
+    }
+
+    #[test]
+    fn ft_transfer_err_2() {
+        // sender is recipient
+        crosscheck(
+            "(define-fungible-token foo)
+             (unwrap-panic (ft-mint? foo u1000 tx-sender))
+             (ft-transfer? foo u10 tx-sender tx-sender)",
+            evaluate("(err u2)"),
+        )
+    }
+
+    #[test]
+    fn ft_transfer_err_3() {
+        // non-positive amount
+        crosscheck(
+            "(define-fungible-token foo)
+             (unwrap-panic (ft-mint? foo u1000 tx-sender))
+             (ft-transfer? foo u0 tx-sender 'ST1PQHQKV0RJXZFY1DGX8MNSNYVE3VGZJSRTPGZGM)",
+            evaluate("(err u3)"),
+        )
+    }
+
     #[test]
     fn validate_define_fungible_tokens() {
         // Reserved keyword
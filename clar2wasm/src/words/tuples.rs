@@ -375,6 +375,23 @@ mod tests {
         crosscheck(snippet, Ok(Some(expected)));
     }
 
+    #[test]
+    fn tuple_fields_in_canonical_order() {
+        crosscheck(
+            "(let ((t (tuple (b 2) (a 1)))) (list (get a t) (get b t)))",
+            evaluate("(list 1 2)"),
+        );
+    }
+
+    #[test]
+    fn tuple_fields_of_different_types_in_canonical_order() {
+        crosscheck(
+            r#"(let ((t (tuple (c "hello") (b u2) (a 0x01))))
+                 {a: (get a t), b: (get b t), c: (get c t)})"#,
+            evaluate(r#"{a: 0x01, b: u2, c: "hello"}"#),
+        );
+    }
+
     //
     // Module with tests that should only be executed
     // when running Clarity::V2 or Clarity::v3.
@@ -43,3 +43,51 @@ fn test_clar2wasm_with_input() {
 
     temp.close().unwrap();
 }
+
+#[test]
+fn test_clar2wasm_json_diagnostics() {
+    use assert_fs::prelude::*;
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    let input = temp.child("unclosed.clar");
+    input
+        .write_str("(define-read-only (get-one)\n  u1")
+        .unwrap();
+
+    assert_cmd::Command::cargo_bin("clar2wasm")
+        .unwrap()
+        .arg(input.path())
+        .arg("--format")
+        .arg("json")
+        .assert()
+        .stdout(predicates::str::starts_with("[{"))
+        .stdout(predicates::str::contains(r#""level":"Error""#))
+        .stdout(predicates::str::contains(r#""start_line":"#))
+        .failure();
+
+    temp.close().unwrap();
+}
+
+#[test]
+fn test_clar2wasm_json_diagnostics_error_code() {
+    use assert_fs::prelude::*;
+
+    let temp = assert_fs::TempDir::new().unwrap();
+    let input = temp.child("mismatch.clar");
+    input
+        .write_str("(define-read-only (get-one) (+ 1 u1))")
+        .unwrap();
+
+    // The code is the name of the CheckErrors variant, which tools can rely
+    // on, unlike the message.
+    assert_cmd::Command::cargo_bin("clar2wasm")
+        .unwrap()
+        .arg(input.path())
+        .arg("--format")
+        .arg("json")
+        .assert()
+        .stdout(predicates::str::contains(r#""code":"TypeError""#))
+        .failure();
+
+    temp.close().unwrap();
+}
@@ -21,6 +21,63 @@ use clarity::vm::types::{
 };
 use clarity::vm::{ClarityVersion, ContractContext, Value};
 use hex::FromHex;
+use wasmparser::{
+    FuncValidatorAllocations, Name, NameSectionReader, Parser, Payload, ValidPayload, Validator,
+};
+
+/// Validates every function of a compiled module independently, so that a
+/// stack-imbalance bug is reported along with the name of the offending
+/// function instead of a single error for the whole module.
+fn validate_functions(wasm: &[u8]) -> Result<(), String> {
+    let mut validator = Validator::new();
+    let mut names = HashMap::new();
+    let mut failures = vec![];
+
+    for payload in Parser::new(0).parse_all(wasm) {
+        let payload = payload.map_err(|e| e.to_string())?;
+
+        // The name section comes after the code section, so we keep the
+        // names around and resolve the failing indices at the end.
+        if let Payload::CustomSection(reader) = &payload {
+            if reader.name() == "name" {
+                for subsection in NameSectionReader::new(reader.data(), reader.data_offset()) {
+                    if let Ok(Name::Function(map)) = subsection {
+                        for naming in map.into_iter().flatten() {
+                            names.insert(naming.index, naming.name.to_owned());
+                        }
+                    }
+                }
+            }
+        }
+
+        if let ValidPayload::Func(to_validate, body) =
+            validator.payload(&payload).map_err(|e| e.to_string())?
+        {
+            let index = to_validate.index;
+            let mut func_validator =
+                to_validate.into_validator(FuncValidatorAllocations::default());
+            if let Err(e) = func_validator.validate(&body) {
+                failures.push((index, e.to_string()));
+            }
+        }
+    }
+
+    if failures.is_empty() {
+        return Ok(());
+    }
+
+    Err(failures
+        .into_iter()
+        .map(|(index, error)| {
+            let name = names
+                .get(&index)
+                .cloned()
+                .unwrap_or_else(|| format!("<function {index}>"));
+            format!("{name}: {error}")
+        })
+        .collect::<Vec<_>>()
+        .join("\n"))
+}
 
 /// This macro provides a convenient way to test contract initialization.
 /// In order, it takes as parameters:
@@ -89,7 +146,11 @@ macro_rules! test_multi_contract_init {
                 let mut contract_context =
                     ContractContext::new(contract_id.clone(), ClarityVersion::Clarity2);
                 // compile_result.module.emit_wasm_file("test.wasm").unwrap();
-                contract_context.set_wasm_module(compile_result.module.emit_wasm());
+                let wasm = compile_result.module.emit_wasm();
+                if let Err(e) = validate_functions(&wasm) {
+                    panic!("Invalid function in contract {contract}:\n{e}");
+                }
+                contract_context.set_wasm_module(wasm);
 
                 let mut global_context = GlobalContext::new(
                     false,
@@ -696,6 +757,18 @@ test_contract_call_response!(
     }
 );
 
+test_contract_call_error!(
+    test_ft_mint_too_many,
+    "tokens",
+    "bar-mint-too-many",
+    |error: Error| {
+        assert!(matches!(
+            error,
+            Error::Runtime(RuntimeErrorType::SupplyOverflow(1000001, 1000000), _)
+        ));
+    }
+);
+
 test_contract_call_response!(
     test_ft_balance_0,
     "tokens",
@@ -4204,3 +4277,45 @@ test_contract_call_response!(
         assert_eq!(*response.data, Value::Int(42));
     }
 );
+
+#[test]
+fn fixture_contracts_pass_function_validation() {
+    let contracts_dir = format!("{}/tests/contracts", env!("CARGO_MANIFEST_DIR"));
+    let mut paths: Vec<_> = std::fs::read_dir(contracts_dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().path())
+        .filter(|path| path.extension().is_some_and(|ext| ext == "clar"))
+        .collect();
+    paths.sort();
+
+    for path in paths {
+        let contract_name = path.file_stem().unwrap().to_str().unwrap();
+        let contract_id = QualifiedContractIdentifier::new(
+            StandardPrincipalData::transient(),
+            contract_name.into(),
+        );
+        let contract_str = std::fs::read_to_string(&path).unwrap();
+
+        let mut clarity_store = MemoryBackingStore::new();
+        let compile_result = clarity_store.as_analysis_db().execute(|analysis_db| {
+            compile(
+                contract_str.as_str(),
+                &contract_id,
+                LimitedCostTracker::new_free(),
+                ClarityVersion::Clarity2,
+                StacksEpochId::latest(),
+                analysis_db,
+            )
+            .map_err(|_| CheckErrors::Expects("Compilation failure".to_string()))
+        });
+
+        // Some fixtures exercise compilation errors, those have nothing to validate.
+        let Ok(mut compile_result) = compile_result else {
+            continue;
+        };
+
+        if let Err(e) = validate_functions(&compile_result.module.emit_wasm()) {
+            panic!("Invalid function in contract {contract_name}:\n{e}");
+        }
+    }
+}